@@ -0,0 +1,179 @@
+//! BIP-353 human-readable payment name resolution.
+//!
+//! BIP-353 (https://github.com/bitcoin/bips/blob/master/bip-0353.mediawiki)
+//! maps an identifier like `alice@example.com` to an on-chain address via a
+//! DNSSEC-signed TXT record at `user.user._bitcoin-payment.domain.`. The
+//! record's value is a `bitcoin:`-style URI carrying the address.
+//!
+//! DNSSEC chain validation (RFC 9102: DNSKEY/DS/RRSIG verified from the root
+//! trust anchors down, following wildcard/CNAME indirection) is delegated to
+//! `hickory_resolver`'s own validator rather than re-implemented here -- we
+//! configure it to validate, and treat an insecure/bogus answer as a failed
+//! resolution. Our job is constructing the right query name and
+//! reassembling/parsing the TXT payload exactly as it arrived on the wire.
+
+use crate::crypto::NetworkRecognition;
+use crate::models::AddressLabel;
+use anyhow::{Context, Result, anyhow};
+use hickory_resolver::TokioResolver;
+use hickory_resolver::config::{ResolverConfig, ResolverOpts};
+use hickory_resolver::name_server::TokioConnectionProvider;
+
+/// Resolves `identifier` (`user@domain`) to an [`AddressLabel`] via its
+/// BIP-353 DNSSEC TXT record. `label` on the returned `AddressLabel` is the
+/// original human-readable identifier, not the resolved address.
+pub async fn resolve(identifier: &str) -> Result<AddressLabel> {
+    let (user, domain) = parse_identifier(identifier)
+        .ok_or_else(|| anyhow!("'{identifier}' is not a valid user@domain identifier"))?;
+
+    let name = query_name(user, domain);
+
+    let resolver = dnssec_validating_resolver();
+    let lookup = resolver
+        .txt_lookup(name.as_str())
+        .await
+        .with_context(|| format!("DNSSEC-validated TXT lookup failed for {name}"))?;
+
+    let payload_bytes = lookup
+        .iter()
+        .find_map(|txt| {
+            let reassembled = reassemble_txt_chunks(txt.txt_data());
+            String::from_utf8(reassembled).ok()
+        })
+        .ok_or_else(|| anyhow!("No valid BIP-353 TXT payload found at {name}"))?;
+
+    let address = parse_bitcoin_uri(&payload_bytes)
+        .ok_or_else(|| anyhow!("TXT payload at {name} is not a bitcoin: URI"))?;
+
+    Ok(AddressLabel {
+        network: address.guess_network(),
+        address: address.to_canonical_address().into_owned(),
+        label: identifier.to_string(),
+        advisory: None,
+    })
+}
+
+/// Splits `user@domain` into its two halves; `None` if `identifier` doesn't
+/// contain exactly one `@`, or either half is empty.
+fn parse_identifier(identifier: &str) -> Option<(&str, &str)> {
+    let (user, domain) = identifier.split_once('@')?;
+
+    if user.is_empty() || domain.is_empty() || domain.contains('@') {
+        return None;
+    }
+
+    Some((user, domain))
+}
+
+/// Builds the BIP-353 query name `{user}.user._bitcoin-payment.{domain}.`.
+fn query_name(user: &str, domain: &str) -> String {
+    let domain = domain.trim_end_matches('.');
+    format!("{user}.user._bitcoin-payment.{domain}.")
+}
+
+/// Reassembles a TXT record's wire-format character-string chunks (each up
+/// to 255 bytes) into the original payload, in the order they were received.
+fn reassemble_txt_chunks(chunks: &[Box<[u8]>]) -> Vec<u8> {
+    chunks.iter().flat_map(|chunk| chunk.iter().copied()).collect()
+}
+
+/// Extracts the address from a `bitcoin:<address>[?params]` URI; `None` if
+/// `payload` isn't such a URI.
+fn parse_bitcoin_uri(payload: &str) -> Option<&str> {
+    let rest = payload.trim().strip_prefix("bitcoin:")?;
+    let address = rest.split(['?', '#']).next()?;
+
+    if address.is_empty() { None } else { Some(address) }
+}
+
+// `ResolverOpts::validate` only does anything if `hickory-resolver` was built
+// with a DNSSEC crypto backend: without one of these features, `validate` is
+// silently a no-op and every TXT record -- signed, unsigned, or outright
+// bogus -- resolves the same way. Catch that at compile time rather than
+// shipping a resolver that looks like it validates but doesn't.
+#[cfg(not(any(feature = "dnssec-ring", feature = "dnssec-aws-lc-rs")))]
+compile_error!(
+    "bip353::resolve depends on hickory-resolver's DNSSEC validation; enable its \
+     \"dnssec-ring\" or \"dnssec-aws-lc-rs\" Cargo feature, otherwise ResolverOpts::validate \
+     is a no-op and unsigned/bogus records resolve as if they were DNSSEC-valid."
+);
+
+/// A resolver configured to validate the DNSSEC chain of trust (RFC 9102)
+/// down from the built-in root trust anchors; an insecure or bogus answer
+/// surfaces as a lookup error rather than silently resolving. Requires
+/// `hickory-resolver`'s `dnssec-ring`/`dnssec-aws-lc-rs` feature -- see the
+/// `compile_error!` above.
+fn dnssec_validating_resolver() -> TokioResolver {
+    let mut opts = ResolverOpts::default();
+    opts.validate = true;
+
+    TokioResolver::builder_with_config(ResolverConfig::cloudflare(), TokioConnectionProvider::default())
+        .with_options(opts)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_identifier_splits_user_and_domain() {
+        assert_eq!(parse_identifier("alice@example.com"), Some(("alice", "example.com")));
+    }
+
+    #[test]
+    fn parse_identifier_rejects_malformed_input() {
+        assert_eq!(parse_identifier("no-at-sign"), None);
+        assert_eq!(parse_identifier("@example.com"), None);
+        assert_eq!(parse_identifier("alice@"), None);
+        assert_eq!(parse_identifier("alice@foo@example.com"), None);
+    }
+
+    #[test]
+    fn query_name_matches_bip353_convention() {
+        assert_eq!(
+            query_name("alice", "example.com"),
+            "alice.user._bitcoin-payment.example.com."
+        );
+        // A trailing dot on the domain shouldn't be doubled.
+        assert_eq!(
+            query_name("alice", "example.com."),
+            "alice.user._bitcoin-payment.example.com."
+        );
+    }
+
+    #[test]
+    fn reassemble_txt_chunks_concatenates_in_order() {
+        let chunks: Vec<Box<[u8]>> = vec![
+            b"bitcoin:"[..].into(),
+            b"bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"[..].into(),
+        ];
+        assert_eq!(
+            reassemble_txt_chunks(&chunks),
+            b"bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq".to_vec()
+        );
+    }
+
+    #[test]
+    fn parse_bitcoin_uri_strips_scheme_and_query() {
+        assert_eq!(
+            parse_bitcoin_uri("bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=0.1"),
+            Some("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+        );
+    }
+
+    #[test]
+    fn parse_bitcoin_uri_rejects_non_bitcoin_payload() {
+        assert_eq!(parse_bitcoin_uri("not a bitcoin uri"), None);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires live DNS resolution against a public DNSSEC test domain"]
+    async fn resolve_rejects_domain_with_bogus_dnssec() {
+        // dnssec-failed.org intentionally serves an expired RRSIG so any
+        // validating resolver must refuse to resolve anything under it. If
+        // this ever returns `Ok`, DNSSEC validation isn't actually running.
+        let result = resolve("test@dnssec-failed.org").await;
+        assert!(result.is_err());
+    }
+}
@@ -1,75 +1,157 @@
 use crate::crypto::NetworkRecognition;
-use crate::csv;
 use crate::externals::evaluate_all;
 use crate::models::AddressFormat;
-use crate::models::{AddressLabel, AdvisoryLevel};
+use crate::models::{AddressLabel, AdvisoryCode, AdvisoryLevel};
+use crate::poisoning;
+use crate::store::Store;
 use arboard::Clipboard;
-use log::info;
-use std::sync::{Arc, Mutex};
-use std::thread;
-use std::time::Duration;
-use tokio::runtime::Runtime;
-use tokio::sync::mpsc;
+use log::{error, info};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot};
 
 const MAX_WALLET_ADDRESS_LENGTH: usize = 70;
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// A clipboard change must be seen unchanged for this long before it's acted
+/// on, so a clipboard manager/app rewriting the clipboard in several quick
+/// steps doesn't trigger a prompt for an intermediate, incomplete value.
+const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(150);
 
-pub fn start_listening(
-    csv_context: Arc<Mutex<csv::Context>>,
+/// Watches the system clipboard for changed content that looks like a
+/// wallet address, emitting `(AdvisoryLevel, AddressLabel)` pairs over `tx`.
+///
+/// Runs as a plain async task on the caller's runtime (no dedicated
+/// `Runtime`/thread of its own) and races the polling interval against
+/// `cancel`, so dropping (or firing) the paired `oneshot::Sender` stops the
+/// watcher immediately instead of waiting out a sleep.
+pub async fn start_listening(
+    store: Arc<Store>,
     tx: mpsc::UnboundedSender<(AdvisoryLevel, AddressLabel)>,
+    mut cancel: oneshot::Receiver<()>,
 ) {
-    let runtime = Runtime::new().expect("Failed to new tokio::runtime::Runtime");
     let mut clipboard = Clipboard::new().expect("Failed to new arboard::Clipboard");
     let mut prev_content = String::new();
+    let mut pending: Option<(String, Instant)> = None;
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
 
     loop {
-        thread::sleep(Duration::from_millis(50));
+        tokio::select! {
+            _ = &mut cancel => {
+                info!("Clipboard watcher received cancellation signal, shutting down");
+                break;
+            }
+            _ = interval.tick() => {
+                poll_once(&mut clipboard, &mut prev_content, &mut pending, &store, &tx).await;
+            }
+        }
 
         if tx.is_closed() {
             break;
         }
+    }
+}
+
+async fn poll_once(
+    clipboard: &mut Clipboard,
+    prev_content: &mut String,
+    pending: &mut Option<(String, Instant)>,
+    store: &Arc<Store>,
+    tx: &mpsc::UnboundedSender<(AdvisoryLevel, AddressLabel)>,
+) {
+    let Ok(content) = clipboard.get_text() else {
+        return;
+    };
+
+    if content.len() > MAX_WALLET_ADDRESS_LENGTH
+        || content == *prev_content
+        || content.guess_network() == AddressFormat::default()
+    {
+        *pending = None;
+        return;
+    }
 
-        if let Ok(content) = clipboard.get_text() {
-            if content.len() > MAX_WALLET_ADDRESS_LENGTH
-                || content == prev_content
-                || content.guess_network() == AddressFormat::default()
-            {
-                continue;
-            } else {
-                prev_content = content.clone();
+    // Debounce: only act once `content` has been seen unchanged for
+    // `DEBOUNCE_INTERVAL` across polls.
+    match pending {
+        Some((pending_content, first_seen)) if *pending_content == content => {
+            if first_seen.elapsed() < DEBOUNCE_INTERVAL {
+                return;
             }
+        }
+        _ => {
+            *pending = Some((content.clone(), Instant::now()));
+            return;
+        }
+    }
+    *pending = None;
+    *prev_content = content.clone();
+
+    let network = content.guess_network();
+    let address = content.to_canonical_address();
+    // ⚠️ Pls note that`address` may not be a eligible wallet address even we called `to_canonical_address`.
+    //    We only standardized its string format for following string comparison operations.
 
-            let network = content.guess_network();
-            let address = content.to_canonical_address();
-            // ⚠️ Pls note that`address` may not be a eligible wallet address even we called `to_canonical_address`.
-            //    We only standardized its string format for following string comparison operations.
+    match store.is_muted(&network, &address) {
+        Ok(true) => {
+            info!("Ignoring clipboard address muted by a persisted MuteIntent: {address}");
+            return;
+        }
+        Ok(false) => {}
+        Err(e) => error!("Failed to check mute rules: {e}"),
+    }
 
-            if let Some(address_label) = csv_context.lock().unwrap().find(&network, &address) {
-                info!("👀 Found existing label in CSV: {:?}", address_label);
-                let _ = tx.send((AdvisoryLevel::Known, address_label.clone()));
-            } else {
-                let address = address.to_string();
+    let stored_label = store.get(&network, &address).unwrap_or_else(|e| {
+        error!("Failed to read label store: {e}");
+        None
+    });
 
-                let _ = tx.send((
-                    AdvisoryLevel::Unknown,
-                    AddressLabel {
-                        network,
-                        address: address.clone(),
-                        label: "🔍 Checking Label...".to_string(),
-                    },
-                ));
+    if let Some(address_label) = stored_label {
+        info!("👀 Found existing label in store: {:?}", address_label);
+        let _ = tx.send((AdvisoryLevel::Known, address_label));
+    } else if let Some(poisoning_match) = {
+        let trusted = store.trusted_addresses(&network).unwrap_or_else(|e| {
+            error!("Failed to read trusted address set: {e}");
+            Vec::new()
+        });
+        poisoning::detect(&address, &trusted, poisoning::DEFAULT_AFFIX_LEN)
+    } {
+        let level = match poisoning_match {
+            poisoning::PoisoningMatch::Exact => AdvisoryLevel::Danger,
+            poisoning::PoisoningMatch::Loose => AdvisoryLevel::Warning,
+        };
+        info!("🧪 Flagged possible address-poisoning ({:?}): {address}", poisoning_match);
+        let _ = tx.send((
+            level,
+            AddressLabel {
+                network,
+                address: address.to_string(),
+                label: "⚠️ Possible Address Poisoning".to_string(),
+                advisory: Some(AdvisoryCode::AddressPoisoning.tag("affix_len", poisoning::DEFAULT_AFFIX_LEN.to_string())),
+            },
+        ));
+    } else {
+        let address = address.to_string();
 
-                let tx = tx.clone();
+        let _ = tx.send((
+            AdvisoryLevel::Unknown,
+            AddressLabel {
+                network,
+                address: address.clone(),
+                label: "🔍 Checking Label...".to_string(),
+                advisory: None,
+            },
+        ));
 
-                runtime.spawn(async move {
-                    if let Ok((advisory_level, address_label)) = evaluate_all(&address).await {
-                        info!(
-                            "🤖 Found address label: {:?} with level: {:?} from external APIs",
-                            address_label, advisory_level
-                        );
-                        let _ = tx.send((advisory_level, address_label));
-                    }
-                });
+        let tx = tx.clone();
+
+        tokio::spawn(async move {
+            if let Ok((advisory_level, address_label)) = evaluate_all(&address).await {
+                info!(
+                    "🤖 Found address label: {:?} with level: {:?} from external APIs",
+                    address_label, advisory_level
+                );
+                let _ = tx.send((advisory_level, address_label));
             }
-        }
+        });
     }
 }
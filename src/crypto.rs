@@ -1,7 +1,9 @@
 use crate::models::AddressFormat;
 use bitcoin::Address as BitcoinAddress;
 use bitcoin::Network as BitcoinNetwork;
+use blake2::Blake2b512;
 use bs58;
+use curve25519_dalek::edwards::CompressedEdwardsY;
 use regex::Regex;
 use sha2::{Digest, Sha256};
 use sha3::Keccak256;
@@ -32,24 +34,58 @@ static REGEX_SOLANA: LazyLock<Regex> =
 static REGEX_POLKADOT: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^[1-9A-HJ-NP-Za-km-z]{47,48}$").unwrap());
 
+/// CashAddr (Bitcoin Cash / eCash) base32 charset; see the `cashaddr_*` helpers below.
+const CASHADDR_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// Prefix `to_canonical_address` fills in when the user omits the `prefix:` part.
+const CASHADDR_DEFAULT_PREFIX: &str = "bitcoincash";
+/// Every prefix a CashAddr payload is accepted under, including eCash's
+/// rebranded prefix and the various testnet/regtest variants.
+const CASHADDR_KNOWN_PREFIXES: [&str; 6] =
+    ["bitcoincash", "bchtest", "bchreg", "ecash", "ectest", "ecregtest"];
+
 pub trait NetworkRecognition {
     fn guess_network(&self) -> AddressFormat;
     fn to_canonical_address(&self) -> Cow<'_, str>;
     fn is_bitcoin(&self) -> bool;
+    fn is_bitcoin_cash(&self) -> bool;
     fn is_evm(&self) -> bool;
     fn is_tron(&self) -> bool;
     fn is_solana(&self) -> bool;
+    /// Whether this is a Solana address *and* decompresses to a genuine
+    /// ed25519 public key -- i.e. a normal wallet, not a program-derived address.
+    fn is_solana_on_curve(&self) -> bool;
+    /// Whether this is a Solana address that's off-curve -- a program-derived
+    /// address (PDA), contract-controlled rather than a user wallet.
+    fn is_solana_pda(&self) -> bool;
     fn is_polkadot(&self) -> bool;
+    /// The SS58 network identifier embedded in this address (`0` for
+    /// Polkadot, `2` for Kusama, `42` for generic Substrate, etc. -- see
+    /// `ss58_chain_name`); `None` if this isn't a valid SS58Check address.
+    fn ss58_network_id(&self) -> Option<u16>;
+    /// Tron and EVM addresses are two encodings of the same 20-byte account
+    /// hash. Converts either one to its `0x...` EVM form; `None` if
+    /// `guess_network()` is neither Tron nor EVM, or the Tron checksum fails.
+    fn to_evm_address(&self) -> Option<String>;
+    /// The Tron-encoding counterpart of `to_evm_address`: converts either a
+    /// Tron or an EVM address to its `T...` Tron form.
+    fn to_tron_address(&self) -> Option<String>;
 }
 
 impl NetworkRecognition for str {
     fn guess_network(&self) -> AddressFormat {
         match () {
             _ if self.is_bitcoin() => AddressFormat::Bitcoin,
+            _ if self.is_bitcoin_cash() => AddressFormat::BitcoinCash,
             _ if self.is_evm() => AddressFormat::EVM,
             _ if self.is_tron() => AddressFormat::Tron,
+            _ if self.is_solana_pda() => AddressFormat::SolanaProgram,
             _ if self.is_solana() => AddressFormat::Solana,
-            _ if self.is_polkadot() => AddressFormat::Polkadot,
+            _ if self.is_polkadot() => match self.ss58_network_id() {
+                Some(0) => AddressFormat::Polkadot,
+                Some(2) => AddressFormat::Kusama,
+                _ => AddressFormat::Substrate,
+            },
+            _ if self.ss58_network_id().is_some() => AddressFormat::Substrate,
             _ => AddressFormat::default(),
         }
     }
@@ -110,6 +146,24 @@ impl NetworkRecognition for str {
                 }
             }
 
+            AddressFormat::BitcoinCash => {
+                let has_upper = self.chars().any(|c| c.is_uppercase());
+                let lower = if has_upper {
+                    Cow::Owned(self.to_lowercase())
+                } else {
+                    Cow::Borrowed(self)
+                };
+
+                if lower.contains(':') {
+                    match lower {
+                        Cow::Owned(lower) => Cow::Owned(lower),
+                        Cow::Borrowed(_) => Cow::Borrowed(self),
+                    }
+                } else {
+                    Cow::Owned(format!("{CASHADDR_DEFAULT_PREFIX}:{lower}"))
+                }
+            }
+
             AddressFormat::Tron => {
                 if self.starts_with('t') {
                     let mut fixed = self.to_string();
@@ -136,6 +190,26 @@ impl NetworkRecognition for str {
         }
     }
 
+    fn is_bitcoin_cash(&self) -> bool {
+        // CashAddr requires the whole address to be either all-lowercase or
+        // all-uppercase; a mixed-case address is invalid regardless of checksum.
+        if self.chars().any(|c| c.is_uppercase()) && self.chars().any(|c| c.is_lowercase()) {
+            return false;
+        }
+
+        let lower = self.to_lowercase();
+
+        match lower.split_once(':') {
+            Some((prefix, payload)) => {
+                CASHADDR_KNOWN_PREFIXES.contains(&prefix) && cashaddr_verify(prefix, payload)
+            }
+            // No explicit `prefix:` part: try every known prefix against the bare payload.
+            None => CASHADDR_KNOWN_PREFIXES
+                .iter()
+                .any(|&prefix| cashaddr_verify(prefix, &lower)),
+        }
+    }
+
     fn is_evm(&self) -> bool {
         let addr = self.strip_prefix("0x").unwrap_or(self);
 
@@ -184,7 +258,9 @@ impl NetworkRecognition for str {
     }
 
     fn is_solana(&self) -> bool {
-        // IMPORTANT: PDA address also return true
+        // Structural check only: a valid 32-byte base58 string, whether it's
+        // an on-curve wallet key or an off-curve PDA. See `is_solana_on_curve`
+        // / `is_solana_pda` for telling the two apart.
         if !REGEX_SOLANA.is_match(self) {
             return false;
         }
@@ -195,14 +271,278 @@ impl NetworkRecognition for str {
         }
     }
 
+    fn is_solana_on_curve(&self) -> bool {
+        if !self.is_solana() {
+            return false;
+        }
+
+        let Ok(decoded) = bs58::decode(self).into_vec() else {
+            return false;
+        };
+
+        // `is_solana` already checked the length is exactly 32.
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&decoded);
+
+        CompressedEdwardsY(bytes).decompress().is_some()
+    }
+
+    fn is_solana_pda(&self) -> bool {
+        self.is_solana() && !self.is_solana_on_curve()
+    }
+
     fn is_polkadot(&self) -> bool {
-        // IMPORTANT: Does not distinguish between Polkadot and Kusama; any SS58Check address will returns true.
+        // Structural check only: a valid 47-48 char SS58Check address (a
+        // one-byte network identifier) returns true, Polkadot/Kusama/parachain
+        // alike; longer two-byte-identifier addresses return false. See
+        // `ss58_network_id` for telling the chains apart.
         if !REGEX_POLKADOT.is_match(self) {
             return false;
         }
 
         AccountId32::from_ss58check(self).is_ok()
     }
+
+    fn ss58_network_id(&self) -> Option<u16> {
+        ss58_decode(self).map(|(network_id, _account_id)| network_id)
+    }
+
+    fn to_evm_address(&self) -> Option<String> {
+        match self.guess_network() {
+            AddressFormat::EVM => Some(self.to_canonical_address().into_owned()),
+            AddressFormat::Tron => {
+                let decoded = bs58::decode(self).into_vec().ok()?;
+                if decoded.len() != 25 {
+                    return None;
+                }
+
+                let (body, checksum) = decoded.split_at(21);
+                let hash = Sha256::digest(Sha256::digest(body));
+                if &hash[..4] != checksum {
+                    return None;
+                }
+
+                // `body` is `0x41` (the Tron address prefix byte) followed by
+                // the 20-byte account hash.
+                let account_hash = &body[1..];
+                let hex_addr = format!("0x{}", hex_encode(account_hash));
+                Some(hex_addr.to_canonical_address().into_owned())
+            }
+            _ => None,
+        }
+    }
+
+    fn to_tron_address(&self) -> Option<String> {
+        match self.guess_network() {
+            AddressFormat::Tron => Some(self.to_canonical_address().into_owned()),
+            AddressFormat::EVM => {
+                let canonical = self.to_canonical_address();
+                let account_hash = hex_decode(canonical.strip_prefix("0x").unwrap_or(&canonical))?;
+
+                let mut body = Vec::with_capacity(21);
+                body.push(0x41);
+                body.extend_from_slice(&account_hash);
+
+                let hash = Sha256::digest(Sha256::digest(&body));
+                let mut full = body;
+                full.extend_from_slice(&hash[..4]);
+
+                Some(bs58::encode(full).into_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Renders `bytes` as lowercase hex, e.g. `[0xAB, 0x01] -> "ab01"`.
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Parses a lowercase-or-uppercase hex string into bytes; `None` if `s` has
+/// an odd length or contains a non-hex-digit character.
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Decodes a CashAddr `payload` into 5-bit values using `CASHADDR_CHARSET`;
+/// `None` if it's too short to plausibly carry a checksum or contains a
+/// character outside the charset.
+fn cashaddr_decode_payload(payload: &str) -> Option<Vec<u8>> {
+    if payload.len() < 8 {
+        return None;
+    }
+
+    payload
+        .chars()
+        .map(|c| CASHADDR_CHARSET.find(c).map(|i| i as u8))
+        .collect()
+}
+
+/// The CashAddr BCH checksum's 5-bit "polymod", per the CashAddr spec.
+fn cashaddr_polymod(values: &[u8]) -> u64 {
+    let mut c: u64 = 1;
+
+    for &d in values {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ (d as u64);
+
+        if c0 & 0x01 != 0 {
+            c ^= 0x98f2bc8e61;
+        }
+        if c0 & 0x02 != 0 {
+            c ^= 0x79b76d99e2;
+        }
+        if c0 & 0x04 != 0 {
+            c ^= 0xf33e5fb3c4;
+        }
+        if c0 & 0x08 != 0 {
+            c ^= 0xae2eabe2a8;
+        }
+        if c0 & 0x10 != 0 {
+            c ^= 0x1e4f43e470;
+        }
+    }
+
+    c ^ 1
+}
+
+/// Whether `payload` (lowercase, without the `prefix:` part) carries a valid
+/// CashAddr checksum for `prefix` (lowercase, no trailing `:`).
+fn cashaddr_verify(prefix: &str, payload: &str) -> bool {
+    let Some(payload_5bit) = cashaddr_decode_payload(payload) else {
+        return false;
+    };
+
+    let mut expanded: Vec<u8> = prefix.bytes().map(|b| b & 0x1f).collect();
+    expanded.push(0); // separator
+    expanded.extend_from_slice(&payload_5bit);
+
+    cashaddr_polymod(&expanded) == 0
+}
+
+/// Decodes an SS58Check-encoded address into its network identifier and
+/// 32-byte account id, verifying the trailing Blake2b-512-derived checksum
+/// by hand (rather than via `sp_core::crypto::Ss58Codec`) so the numeric
+/// prefix is available without re-parsing. `None` if `address` isn't valid
+/// base58, isn't the length expected for a 32-byte account id, or its
+/// checksum doesn't match.
+fn ss58_decode(address: &str) -> Option<(u16, [u8; 32])> {
+    let decoded = bs58::decode(address).into_vec().ok()?;
+    let first = *decoded.first()?;
+
+    // A first byte in `0..=63` is the whole network identifier; a first
+    // byte in `64..=127` starts a two-byte identifier (`64..=16383`), packed
+    // across a LE/LSB-first bit layout (see the SS58 registry spec) rather
+    // than a plain two-byte integer. A first byte in `128..=255` is reserved
+    // by the spec and isn't a valid prefix at all.
+    let (network_id, prefix_len) = if first < 64 {
+        (first as u16, 1)
+    } else if first > 127 {
+        return None;
+    } else {
+        let second = *decoded.get(1)?;
+        let lower = (first << 2) | (second >> 6);
+        let upper = second & 0b0011_1111;
+        ((lower as u16) | ((upper as u16) << 8), 2)
+    };
+
+    const ACCOUNT_LEN: usize = 32;
+    const CHECKSUM_LEN: usize = 2;
+
+    if decoded.len() != prefix_len + ACCOUNT_LEN + CHECKSUM_LEN {
+        return None;
+    }
+
+    let (body, checksum) = decoded.split_at(prefix_len + ACCOUNT_LEN);
+    let (prefix_bytes, account_bytes) = body.split_at(prefix_len);
+
+    let mut preimage = b"SS58PRE".to_vec();
+    preimage.extend_from_slice(prefix_bytes);
+    preimage.extend_from_slice(account_bytes);
+    let hash = Blake2b512::digest(&preimage);
+
+    if &hash[..CHECKSUM_LEN] != checksum {
+        return None;
+    }
+
+    let mut account_id = [0u8; ACCOUNT_LEN];
+    account_id.copy_from_slice(account_bytes);
+    Some((network_id, account_id))
+}
+
+/// A human-readable chain name for an SS58 network identifier, per the
+/// ss58-registry (https://github.com/paritytech/ss58-registry). Unrecognized
+/// identifiers (most parachains don't have a dedicated prefix) fall back to
+/// a generic label carrying the raw number.
+pub fn ss58_chain_name(network_id: u16) -> String {
+    match network_id {
+        0 => "Polkadot".to_string(),
+        2 => "Kusama".to_string(),
+        42 => "Substrate (generic)".to_string(),
+        other => format!("Unknown Substrate chain (prefix {other})"),
+    }
+}
+
+/// Classifies `address` purely by its own format/checksum, independent of
+/// any caller-supplied `AddressFormat` -- the two are expected to agree, and
+/// a mismatch is a sign the address was tampered with or mislabeled. The
+/// returned `bool` is whether the address is well-formed for the detected
+/// network; for EVM this additionally requires a mixed-case address to pass
+/// its EIP-55 checksum.
+pub fn detect_network(address: &str) -> (AddressFormat, bool) {
+    let network = address.guess_network();
+
+    let valid = match network {
+        AddressFormat::EVM => is_valid_eip55(address),
+        AddressFormat::Other => false,
+        _ => true,
+    };
+
+    (network, valid)
+}
+
+/// Validates an EVM address's EIP-55 mixed-case checksum: for each
+/// alphabetic hex char, it must be uppercase exactly when the corresponding
+/// nibble of `keccak256(lowercased_address_without_0x)` is >= 8. An address
+/// that's entirely lowercase or entirely uppercase carries no checksum
+/// information and is treated as valid.
+fn is_valid_eip55(address: &str) -> bool {
+    let addr = address.strip_prefix("0x").unwrap_or(address);
+
+    let is_all_lower = addr.chars().all(|c| !c.is_alphabetic() || c.is_lowercase());
+    let is_all_upper = addr.chars().all(|c| !c.is_alphabetic() || c.is_uppercase());
+    if is_all_lower || is_all_upper {
+        return true;
+    }
+
+    let hash = Keccak256::digest(addr.to_lowercase().as_bytes());
+
+    addr.chars().enumerate().all(|(i, c)| {
+        if !c.is_alphabetic() {
+            return true;
+        }
+
+        let hash_byte = hash[i / 2];
+        let hash_nibble = if i % 2 == 0 {
+            (hash_byte >> 4) & 0xF
+        } else {
+            hash_byte & 0xF
+        };
+
+        if hash_nibble >= 8 {
+            c.is_uppercase()
+        } else {
+            c.is_lowercase()
+        }
+    })
 }
 
 #[cfg(test)]
@@ -247,6 +587,49 @@ mod tests {
         assert!(!"bc1pnotarealaddressatall".is_bitcoin()); // not a related address
     }
 
+    #[test]
+    fn test_is_bitcoin_cash_should_return_true() {
+        // Well-known CashAddr P2PKH test vector shared across BCH libraries.
+        assert!("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a".is_bitcoin_cash());
+        // Same payload, no explicit prefix.
+        assert!("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a".is_bitcoin_cash());
+        // Mixed-case is invalid, but an all-uppercase address is fine.
+        assert!("BITCOINCASH:QPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVY22GDX6A".is_bitcoin_cash());
+        // eCash's rebranded prefix over the same checksum algorithm.
+        let all_zero_payload = build_cashaddr_for_test("ecash", &[0u8; 34]);
+        assert!(all_zero_payload.is_bitcoin_cash());
+    }
+
+    #[test]
+    fn test_is_bitcoin_cash_should_return_false() {
+        assert!(!"hello world".is_bitcoin_cash());
+        assert!(!"".is_bitcoin_cash());
+        // Unknown prefix.
+        assert!(!"notarealprefix:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a".is_bitcoin_cash());
+        // Mixed case.
+        assert!(!"bitcoincash:QPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVY22GDX6A".is_bitcoin_cash());
+        // Tampered checksum (last char flipped).
+        assert!(!"bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6p".is_bitcoin_cash());
+    }
+
+    /// Builds a valid CashAddr string from raw 5-bit `payload` values by
+    /// computing and appending this module's own checksum -- a self-oracle
+    /// for cases that aren't already covered by the external test vector above.
+    fn build_cashaddr_for_test(prefix: &str, payload: &[u8]) -> String {
+        let mut expanded: Vec<u8> = prefix.bytes().map(|b| b & 0x1f).collect();
+        expanded.push(0);
+        expanded.extend_from_slice(payload);
+        expanded.extend_from_slice(&[0u8; 8]);
+
+        let poly = cashaddr_polymod(&expanded);
+        let checksum: Vec<u8> = (0..8).map(|i| ((poly >> (5 * (7 - i))) & 0x1f) as u8).collect();
+
+        let to_char = |v: u8| CASHADDR_CHARSET.as_bytes()[v as usize] as char;
+        let payload_str: String = payload.iter().chain(&checksum).map(|&v| to_char(v)).collect();
+
+        format!("{prefix}:{payload_str}")
+    }
+
     #[test]
     fn test_is_evm_should_return_true() {
         assert!("0xdAC17F958D2ee523a2206206994597C13D831ec7".is_evm()); // checksum
@@ -308,6 +691,33 @@ mod tests {
         assert!(!"TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t".is_solana());
     }
 
+    #[test]
+    fn test_is_solana_on_curve_distinguishes_wallet_from_pda() {
+        // The ed25519 basepoint is, by construction, a valid on-curve point.
+        let wallet_bytes = curve25519_dalek::constants::ED25519_BASEPOINT_COMPRESSED.to_bytes();
+        let wallet_addr = bs58::encode(wallet_bytes).into_string();
+        assert!(wallet_addr.is_solana_on_curve());
+        assert!(!wallet_addr.is_solana_pda());
+
+        // Scan for a genuinely off-curve 32-byte value (about half of all
+        // byte patterns are off-curve) to use as a self-verified PDA example.
+        let pda_bytes = (0u8..=255u8)
+            .map(|b| {
+                let mut bytes = [0u8; 32];
+                bytes[0] = b;
+                bytes
+            })
+            .find(|&bytes| CompressedEdwardsY(bytes).decompress().is_none())
+            .expect("at least one of the first 256 candidates should be off-curve");
+        let pda_addr = bs58::encode(pda_bytes).into_string();
+        assert!(pda_addr.is_solana());
+        assert!(!pda_addr.is_solana_on_curve());
+        assert!(pda_addr.is_solana_pda());
+
+        assert_eq!(wallet_addr.guess_network(), AddressFormat::Solana);
+        assert_eq!(pda_addr.guess_network(), AddressFormat::SolanaProgram);
+    }
+
     #[test]
     fn test_is_polkadot_should_return_true() {
         assert!("1FRMM8PEiWXYax7rpS6X4XZX1aAAxSWx1CrKTyrVYhV24fg".is_polkadot());
@@ -322,4 +732,150 @@ mod tests {
         assert!(!"".is_polkadot());
         assert!(!"invalid_polkadot_address".is_polkadot());
     }
+
+    #[test]
+    fn test_ss58_network_id_known_addresses() {
+        // Polkadot mainnet prefix (0).
+        assert_eq!(
+            "1FRMM8PEiWXYax7rpS6X4XZX1aAAxSWx1CrKTyrVYhV24fg".ss58_network_id(),
+            Some(0)
+        );
+        // The well-known "Alice" dev account, in the generic Substrate (42) format.
+        assert_eq!(
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".ss58_network_id(),
+            Some(42)
+        );
+        assert_eq!("not an ss58 address".ss58_network_id(), None);
+    }
+
+    #[test]
+    fn test_guess_network_disambiguates_polkadot_and_substrate() {
+        assert_eq!(
+            "1FRMM8PEiWXYax7rpS6X4XZX1aAAxSWx1CrKTyrVYhV24fg".guess_network(),
+            AddressFormat::Polkadot
+        );
+        assert_eq!(
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".guess_network(),
+            AddressFormat::Substrate
+        );
+    }
+
+    #[test]
+    fn test_ss58_chain_name_maps_known_prefixes() {
+        assert_eq!(ss58_chain_name(0), "Polkadot");
+        assert_eq!(ss58_chain_name(2), "Kusama");
+        assert_eq!(ss58_chain_name(42), "Substrate (generic)");
+        assert!(ss58_chain_name(9999).contains("9999"));
+    }
+
+    #[test]
+    fn test_ss58_decode_two_byte_network_identifier() {
+        // A network id in 64..=16383 needs a bit-packed two-byte prefix that
+        // can't be read off a real-world address by eye, so this test builds
+        // one by hand and confirms the decode round-trips.
+        let network_id: u16 = 12345;
+        let account_bytes = [7u8; 32];
+        let address = build_ss58_for_test(network_id, account_bytes);
+
+        assert_eq!(address.ss58_network_id(), Some(network_id));
+        assert_eq!(address.guess_network(), AddressFormat::Substrate);
+    }
+
+    #[test]
+    fn test_ss58_network_id_rejects_reserved_prefix_byte() {
+        // `128..=255` is reserved by the SS58 spec, not a valid 2-byte prefix
+        // lead byte -- the checksum doesn't even need to be right for this
+        // to be rejected, since the bound check runs before verifying it.
+        let mut raw = vec![200u8];
+        raw.extend_from_slice(&[0u8; 32]);
+        raw.extend_from_slice(&[0u8; 2]);
+        let address = bs58::encode(raw).into_string();
+
+        assert_eq!(address.ss58_network_id(), None);
+    }
+
+    fn build_ss58_for_test(network_id: u16, account_bytes: [u8; 32]) -> String {
+        assert!((64..=16383).contains(&network_id));
+
+        let lower = (network_id & 0xFF) as u8;
+        let upper = (network_id >> 8) as u8;
+        let first = 0b0100_0000 | ((lower >> 2) & 0x3F);
+        let second = ((lower & 0x3) << 6) | upper;
+
+        let mut preimage = b"SS58PRE".to_vec();
+        preimage.push(first);
+        preimage.push(second);
+        preimage.extend_from_slice(&account_bytes);
+        let hash = Blake2b512::digest(&preimage);
+
+        let mut full = vec![first, second];
+        full.extend_from_slice(&account_bytes);
+        full.extend_from_slice(&hash[..2]);
+
+        bs58::encode(full).into_string()
+    }
+
+    #[test]
+    fn test_detect_network_should_return_valid_for_correct_checksum() {
+        assert_eq!(
+            detect_network("0xdAC17F958D2ee523a2206206994597C13D831ec7"),
+            (AddressFormat::EVM, true)
+        );
+        assert_eq!(
+            detect_network("0xdac17f958d2ee523a2206206994597c13d831ec7"),
+            (AddressFormat::EVM, true)
+        );
+    }
+
+    #[test]
+    fn test_detect_network_should_return_invalid_for_bad_checksum() {
+        // Same address as above with the case of the 'e' in its trailing "ec7" flipped.
+        let (network, valid) =
+            detect_network("0xdAC17F958D2ee523a2206206994597C13D831Ec7");
+        assert_eq!(network, AddressFormat::EVM);
+        assert!(!valid);
+    }
+
+    #[test]
+    fn test_detect_network_should_return_invalid_for_unrecognized_format() {
+        assert_eq!(detect_network("not a wallet address"), (AddressFormat::Other, false));
+    }
+
+    #[test]
+    fn test_to_evm_address_and_to_tron_address_round_trip() {
+        let tron = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+
+        let evm = tron.to_evm_address().expect("tron address should convert to evm");
+        assert!(evm.starts_with("0x"));
+        assert_eq!(evm.len(), 42);
+
+        let back = evm.to_tron_address().expect("evm address should convert back to tron");
+        assert_eq!(back, tron);
+    }
+
+    #[test]
+    fn test_to_evm_address_is_identity_for_evm_input() {
+        assert_eq!(
+            "0xdAC17F958D2ee523a2206206994597C13D831ec7".to_evm_address(),
+            Some("0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_tron_address_is_identity_for_tron_input() {
+        let tron = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+        assert_eq!(tron.to_tron_address(), Some(tron.to_string()));
+    }
+
+    #[test]
+    fn test_to_evm_address_returns_none_for_neither_tron_nor_evm() {
+        assert!("hello world".to_evm_address().is_none());
+        assert!("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_evm_address().is_none());
+    }
+
+    #[test]
+    fn test_to_tron_address_returns_none_for_neither_tron_nor_evm() {
+        assert!("hello world".to_tron_address().is_none());
+        assert!("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_tron_address().is_none());
+    }
 }
@@ -0,0 +1,226 @@
+//! Merges verdicts from several [`Evaluation`] providers into one advisory,
+//! rather than trusting whichever provider happens to answer first.
+
+use crate::externals::Evaluation;
+use crate::models::{AddressFormat, AddressLabel, AdvisoryLevel};
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+use log::warn;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A registered provider: its weight determines both how much it counts
+/// towards the `Danger` quorum and, among responders whose label has a
+/// non-default network, whose `AddressLabel` wins.
+struct ConsensusProvider {
+    name: String,
+    provider: Arc<dyn Evaluation + Send + Sync>,
+    weight: f64,
+    timeout: Duration,
+}
+
+/// One provider's raw verdict, surfaced alongside the merged result so the
+/// UI/API can show which sources flagged an address.
+#[derive(Debug, Clone)]
+pub struct ProviderVerdict {
+    pub name: String,
+    pub weight: f64,
+    pub advisory_level: AdvisoryLevel,
+    pub label: AddressLabel,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsensusResult {
+    pub advisory_level: AdvisoryLevel,
+    pub label: AddressLabel,
+    pub breakdown: Vec<ProviderVerdict>,
+}
+
+/// Runs its registered providers concurrently and merges them into a single
+/// verdict: escalates to `Danger` only once the summed weight of providers
+/// reporting `Danger` meets `danger_quorum_threshold` (a fraction of the
+/// weight of providers that actually responded); otherwise takes the
+/// highest level among responders, counting a sub-quorum `Danger` as
+/// `Risky`. Providers that time out or error are
+/// excluded from the quorum denominator entirely, rather than counted as
+/// clean, so one slow API can't silently downgrade a sanctioned hit.
+pub struct ConsensusEvaluator {
+    providers: Vec<ConsensusProvider>,
+    danger_quorum_threshold: f64,
+}
+
+impl ConsensusEvaluator {
+    pub fn builder() -> ConsensusEvaluatorBuilder {
+        ConsensusEvaluatorBuilder::default()
+    }
+
+    pub async fn evaluate_with_breakdown(&self, address: &str) -> ConsensusResult {
+        let responses = join_all(self.providers.iter().map(|p| async move {
+            match tokio::time::timeout(p.timeout, p.provider.evaluate(address)).await {
+                Ok(Ok((advisory_level, label))) => Some(ProviderVerdict {
+                    name: p.name.clone(),
+                    weight: p.weight,
+                    advisory_level,
+                    label,
+                }),
+                Ok(Err(e)) => {
+                    warn!("Provider '{}' errored evaluating {address}: {e}", p.name);
+                    None
+                }
+                Err(_) => {
+                    warn!("Provider '{}' timed out evaluating {address}", p.name);
+                    None
+                }
+            }
+        }))
+        .await;
+
+        let breakdown: Vec<ProviderVerdict> = responses.into_iter().flatten().collect();
+
+        let total_weight: f64 = breakdown.iter().map(|v| v.weight).sum();
+        let danger_weight: f64 = breakdown
+            .iter()
+            .filter(|v| v.advisory_level == AdvisoryLevel::Danger)
+            .map(|v| v.weight)
+            .sum();
+
+        let advisory_level = if total_weight > 0.0
+            && danger_weight / total_weight >= self.danger_quorum_threshold
+        {
+            AdvisoryLevel::Danger
+        } else if let Some(level) = breakdown
+            .iter()
+            // A single provider's `Danger` shouldn't outrank the weighted
+            // quorum gate above; short of quorum it's demoted to `Risky` so
+            // it still surfaces as elevated risk without forcing the same
+            // "DO NOT INTERACT" verdict the quorum failed to reach.
+            .map(|v| match v.advisory_level {
+                AdvisoryLevel::Danger => AdvisoryLevel::Risky,
+                ref level => level.clone(),
+            })
+            .max_by_key(risk_rank)
+        {
+            level
+        } else {
+            // No provider answered at all; a registry hit still beats a
+            // bare "Unknown" verdict.
+            crate::registry::lookup_known_address(address)
+                .map(|(level, _)| level)
+                .unwrap_or(AdvisoryLevel::Unknown)
+        };
+
+        let label = breakdown
+            .iter()
+            .filter(|v| v.label.network != AddressFormat::default())
+            .max_by(|a, b| a.weight.total_cmp(&b.weight))
+            .map(|v| v.label.clone())
+            .unwrap_or_else(|| AddressLabel::from(address));
+
+        ConsensusResult {
+            advisory_level,
+            label,
+            breakdown,
+        }
+    }
+}
+
+#[async_trait]
+impl Evaluation for ConsensusEvaluator {
+    async fn evaluate(&self, address: &str) -> Result<(AdvisoryLevel, AddressLabel)> {
+        let result = self.evaluate_with_breakdown(address).await;
+        Ok((result.advisory_level, result.label))
+    }
+}
+
+fn risk_rank(level: &AdvisoryLevel) -> u8 {
+    match level {
+        AdvisoryLevel::Unknown | AdvisoryLevel::Known => 0,
+        AdvisoryLevel::Warning => 1,
+        AdvisoryLevel::Risky => 2,
+        AdvisoryLevel::Danger => 3,
+    }
+}
+
+#[derive(Default)]
+pub struct ConsensusEvaluatorBuilder {
+    providers: Vec<ConsensusProvider>,
+    danger_quorum_threshold: Option<f64>,
+}
+
+impl ConsensusEvaluatorBuilder {
+    pub fn provider(
+        mut self,
+        name: impl Into<String>,
+        provider: Arc<dyn Evaluation + Send + Sync>,
+        weight: f64,
+        timeout: Duration,
+    ) -> Self {
+        self.providers.push(ConsensusProvider {
+            name: name.into(),
+            provider,
+            weight,
+            timeout,
+        });
+        self
+    }
+
+    /// Fraction (0.0-1.0) of the responding weight that must report `Danger`
+    /// before the merged result escalates. Defaults to 0.5 (simple majority
+    /// of the weight that actually answered).
+    pub fn danger_quorum_threshold(mut self, threshold: f64) -> Self {
+        self.danger_quorum_threshold = Some(threshold);
+        self
+    }
+
+    pub fn build(self) -> ConsensusEvaluator {
+        ConsensusEvaluator {
+            providers: self.providers,
+            danger_quorum_threshold: self.danger_quorum_threshold.unwrap_or(0.5),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeProvider(AdvisoryLevel);
+
+    #[async_trait]
+    impl Evaluation for FakeProvider {
+        async fn evaluate(&self, address: &str) -> Result<(AdvisoryLevel, AddressLabel)> {
+            Ok((self.0.clone(), AddressLabel::from(address)))
+        }
+    }
+
+    #[tokio::test]
+    async fn escalates_to_danger_once_weighted_quorum_is_met() {
+        let evaluator = ConsensusEvaluator::builder()
+            .provider("a", Arc::new(FakeProvider(AdvisoryLevel::Danger)), 2.0, Duration::from_secs(1))
+            .provider("b", Arc::new(FakeProvider(AdvisoryLevel::Known)), 1.0, Duration::from_secs(1))
+            .build();
+
+        let result = evaluator.evaluate_with_breakdown("0xabc").await;
+        assert_eq!(result.advisory_level, AdvisoryLevel::Danger);
+    }
+
+    #[tokio::test]
+    async fn demotes_danger_to_risky_when_quorum_is_not_met() {
+        let evaluator = ConsensusEvaluator::builder()
+            .provider("a", Arc::new(FakeProvider(AdvisoryLevel::Danger)), 1.0, Duration::from_secs(1))
+            .provider("b", Arc::new(FakeProvider(AdvisoryLevel::Known)), 3.0, Duration::from_secs(1))
+            .build();
+
+        let result = evaluator.evaluate_with_breakdown("0xabc").await;
+        assert_eq!(result.advisory_level, AdvisoryLevel::Risky);
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_registry_when_no_provider_responds() {
+        let evaluator = ConsensusEvaluator::builder().build();
+
+        let result = evaluator.evaluate_with_breakdown("0xabc").await;
+        assert_eq!(result.advisory_level, AdvisoryLevel::Unknown);
+    }
+}
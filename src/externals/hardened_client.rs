@@ -0,0 +1,153 @@
+//! Builds the shared `reqwest::Client` used to query third-party sanctions
+//! and scam-list APIs, with an optional hardened transport: a non-default
+//! DNS resolver and/or a SOCKS5 proxy, so a user can avoid leaking both the
+//! DNS lookups and the queried wallet addresses to their default network
+//! path (e.g. by routing everything through Tor).
+
+use anyhow::{Context, Result, bail};
+use hickory_resolver::TokioResolver;
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig};
+use hickory_resolver::name_server::TokioConnectionProvider;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How DNS lookups for the hardened client are resolved.
+#[derive(Clone, Debug)]
+pub enum DnsConfig {
+    /// Use whatever resolver the OS would normally use.
+    SystemDefault,
+    /// Send lookups straight to a fixed upstream DNS server (e.g. `1.1.1.1:53`).
+    FixedUpstream(SocketAddr),
+    /// Resolve via DNS-over-HTTPS against the given server.
+    DnsOverHttps(SocketAddr),
+}
+
+impl Default for DnsConfig {
+    fn default() -> Self {
+        Self::SystemDefault
+    }
+}
+
+/// Configuration for the hardened HTTP client shared by every
+/// [`crate::externals::Evaluation`] provider that talks to a remote API.
+#[derive(Clone, Debug, Default)]
+pub struct HttpClientConfig {
+    dns: DnsConfig,
+    socks5_proxy: Option<String>,
+    fail_closed_on_proxy_unreachable: bool,
+    timeout: Duration,
+    user_agent: String,
+}
+
+impl HttpClientConfig {
+    pub fn builder() -> HttpClientConfigBuilder {
+        HttpClientConfigBuilder::default()
+    }
+}
+
+/// Builder for [`HttpClientConfig`], so every provider constructs its
+/// `reqwest::Client` the same hardened way.
+#[derive(Default)]
+pub struct HttpClientConfigBuilder {
+    dns: DnsConfig,
+    socks5_proxy: Option<String>,
+    fail_closed_on_proxy_unreachable: bool,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+}
+
+impl HttpClientConfigBuilder {
+    pub fn dns(mut self, dns: DnsConfig) -> Self {
+        self.dns = dns;
+        self
+    }
+
+    /// `proxy` is a `socks5h://host:port` (or `socks5://host:port`) URL.
+    pub fn socks5_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.socks5_proxy = Some(proxy.into());
+        self
+    }
+
+    /// If `true`, client construction fails rather than silently falling
+    /// back to a direct connection when the proxy can't be configured.
+    pub fn fail_closed_on_proxy_unreachable(mut self, fail_closed: bool) -> Self {
+        self.fail_closed_on_proxy_unreachable = fail_closed;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn build(self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(self.timeout.unwrap_or(Duration::from_secs(30)))
+            .user_agent(self.user_agent.unwrap_or_else(|| {
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36".to_string()
+            }));
+
+        if let Some(resolver) = build_resolver(&self.dns)? {
+            builder = builder.dns_resolver(resolver);
+        }
+
+        if let Some(proxy_url) = &self.socks5_proxy {
+            match reqwest::Proxy::all(proxy_url) {
+                Ok(proxy) => builder = builder.proxy(proxy),
+                Err(e) if self.fail_closed_on_proxy_unreachable => {
+                    bail!("Refusing to fall back to a direct connection: invalid SOCKS5 proxy {proxy_url}: {e}");
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Invalid SOCKS5 proxy {proxy_url}, falling back to a direct connection: {e}"
+                    );
+                }
+            }
+        }
+
+        builder.build().context("Failed to build hardened HTTP client")
+    }
+}
+
+fn build_resolver(dns: &DnsConfig) -> Result<Option<Arc<dyn Resolve>>> {
+    let resolver_config = match dns {
+        DnsConfig::SystemDefault => return Ok(None),
+        DnsConfig::FixedUpstream(addr) => {
+            ResolverConfig::from_parts(None, vec![], NameServerConfigGroup::from_ips_clear(
+                &[addr.ip()],
+                addr.port(),
+                true,
+            ))
+        }
+        DnsConfig::DnsOverHttps(addr) => ResolverConfig::from_parts(
+            None,
+            vec![],
+            NameServerConfigGroup::from_ips_https(&[addr.ip()], addr.port(), String::new(), true),
+        ),
+    };
+
+    let resolver = TokioResolver::builder_with_config(resolver_config, TokioConnectionProvider::default()).build();
+
+    Ok(Some(Arc::new(HickoryResolver(Arc::new(resolver)))))
+}
+
+/// Adapts a `hickory_resolver::TokioResolver` to `reqwest`'s `Resolve` trait.
+struct HickoryResolver(Arc<TokioResolver>);
+
+impl Resolve for HickoryResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.0.clone();
+        Box::pin(async move {
+            let lookup = resolver.lookup_ip(name.as_str()).await?;
+            let addrs: Addrs = Box::new(lookup.into_iter().map(|ip| SocketAddr::new(ip, 0)));
+            Ok(addrs)
+        })
+    }
+}
@@ -1,16 +1,19 @@
 use crate::crypto::NetworkRecognition;
 use crate::externals::Evaluation;
-use crate::models::{AddressLabel, AdvisoryLevel};
+use crate::models::{AddressLabel, AdvisoryCode, AdvisoryLevel};
 use anyhow::bail;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use git2::build::RepoBuilder;
-use git2::{Direction, FetchOptions, ObjectType, Remote, Repository, ResetType};
+use gix::bstr::ByteSlice;
+use gix::remote::Direction;
+use gix::remote::fetch::Shallow;
 use log::{error, info, warn};
 use serde_json;
 use std::env;
 use std::fs;
+use std::num::NonZeroU32;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
 use tokio;
 use walkdir::WalkDir;
 
@@ -45,6 +48,9 @@ impl Evaluation for RevokeCashWhois {
                         network: address_owned.guess_network(),
                         address: address_owned,
                         label: format!("Known Malicious Drainer ({label}) - Revoke approvals immediately!!!"),
+                        advisory: Some(
+                            AdvisoryCode::MaliciousSpender.tag("source", "revokecash-whois"),
+                        ),
                     },
                 ));
             }
@@ -123,7 +129,7 @@ impl RevokeCashWhois {
     /// Clone the repository if it does not exist, otherwise pull updates
     fn clone_or_pull(&self) -> Result<()> {
         if self.local_repo_path.exists() {
-            match Repository::open(self.local_repo_path.as_os_str()) {
+            match gix::open(&self.local_repo_path) {
                 Ok(_repo) => {
                     info!("🔄 Pulling updates...");
                     self.pull_repo()
@@ -144,20 +150,41 @@ impl RevokeCashWhois {
         }
     }
 
-    /// Get latest commit id of the remote repository
+    /// Get latest commit id of the remote repository by listing its refs,
+    /// without performing a full fetch.
     fn get_remote_latest_commit(&self) -> Result<String> {
-        let mut remote = Remote::create_detached(self.remote_repo_url.as_str())
+        // We only need a `Repository` handle to open a connection against
+        // `remote_repo_url`; the local clone (if any) is reused for this, and
+        // a bare scratch repo is initialised under the temp dir otherwise so
+        // this works before the repository has ever been cloned.
+        let repo = if self.local_repo_path.exists() {
+            gix::open(&self.local_repo_path).context("Failed to open local repository")?
+        } else {
+            gix::init_bare(env::temp_dir().join("RevokeCashWhois.ref-scratch"))
+                .context("Failed to create scratch repository for ref listing")?
+        };
+
+        let remote = repo
+            .remote_at(self.remote_repo_url.as_str())
             .context("Failed to create detached remote")?;
 
-        remote
+        let connection = remote
             .connect(Direction::Fetch)
             .context("Failed to connect to remote")?;
 
-        let refs = remote.list().context("Failed to list remote references")?;
-
-        for head in refs {
-            if head.name() == "refs/heads/main" {
-                return Ok(head.oid().to_string());
+        let refs = connection
+            .ref_map(gix::progress::Discard, Default::default())
+            .context("Failed to list remote references")?
+            .remote_refs;
+
+        for ref_ in refs {
+            if let Some(unpacked) = ref_.unpack() {
+                let (name, target, _peeled) = unpacked;
+                if name.as_bstr() == "refs/heads/main".as_bytes().as_bstr() {
+                    if let Some(target) = target {
+                        return Ok(target.to_string());
+                    }
+                }
             }
         }
 
@@ -171,7 +198,7 @@ impl RevokeCashWhois {
             return None;
         }
 
-        let repo = match Repository::open(&self.local_repo_path) {
+        let repo = match gix::open(&self.local_repo_path) {
             Ok(r) => r,
             Err(_) => {
                 error!("❌ Local directory exist but repo is broken (not a git repo)");
@@ -179,8 +206,8 @@ impl RevokeCashWhois {
             }
         };
 
-        match repo.revparse_single("HEAD") {
-            Ok(object) => Some(object.id().to_string()),
+        match repo.head_id() {
+            Ok(id) => Some(id.to_string()),
             Err(_) => {
                 error!("❌ Local directory exist but repo is broken (HEAD not found)");
                 None
@@ -188,53 +215,79 @@ impl RevokeCashWhois {
         }
     }
 
-    /// Clone the remote repository to the local directory
+    /// Clone the remote repository to the local directory (shallow, depth 1)
     fn clone_repo(&self) -> Result<()> {
         info!(
             "⏬ Cloning repository to {}...",
             self.local_repo_path.to_string_lossy()
         );
 
-        let mut fetch_options = FetchOptions::new();
-        fetch_options.depth(1);
+        let should_interrupt = AtomicBool::new(false);
 
-        let mut builder = RepoBuilder::new();
-        builder.fetch_options(fetch_options);
+        let mut prepare = gix::prepare_clone(self.remote_repo_url.as_str(), &self.local_repo_path)
+            .context("Failed to prepare clone")?
+            .with_shallow(Shallow::DepthAtRemote(
+                NonZeroU32::new(1).expect("1 is non-zero"),
+            ));
 
-        builder
-            .clone(self.remote_repo_url.as_str(), &self.local_repo_path)
-            .context("Failed to clone repository")?;
+        let (mut checkout, _outcome) = prepare
+            .fetch_then_checkout(gix::progress::Discard, &should_interrupt)
+            .context("Failed to fetch repository")?;
+
+        checkout
+            .main_worktree(gix::progress::Discard, &should_interrupt)
+            .context("Failed to checkout worktree")?;
 
         info!("✅ Repo cloning finished successfully.");
         Ok(())
     }
 
-    /// Pull updates from the remote repository
+    /// Pull updates from the remote repository: shallow-fetch `main` and
+    /// hard-reset the worktree to `FETCH_HEAD`.
     fn pull_repo(&self) -> Result<()> {
-        let repo =
-            Repository::open(&self.local_repo_path).context("Failed to open local repository")?;
+        let repo = gix::open(&self.local_repo_path).context("Failed to open local repository")?;
 
-        let mut remote = repo
-            .find_remote("origin")
-            .context("Failed to find 'origin' remote")?;
+        let should_interrupt = AtomicBool::new(false);
 
-        let mut fetch_options = FetchOptions::new();
-        fetch_options.depth(1);
+        let remote = repo
+            .find_remote("origin")
+            .context("Failed to find 'origin' remote")?
+            .with_refspecs(["main"].into_iter(), Direction::Fetch)
+            .context("Failed to set fetch refspec")?;
 
-        remote
-            .fetch(&["main"], Some(&mut fetch_options), None)
+        let outcome = remote
+            .connect(Direction::Fetch)
+            .context("Failed to connect to remote")?
+            .prepare_fetch(gix::progress::Discard, Default::default())
+            .context("Failed to prepare fetch")?
+            .with_shallow(Shallow::DepthAtRemote(
+                NonZeroU32::new(1).expect("1 is non-zero"),
+            ))
+            .receive(gix::progress::Discard, &should_interrupt)
             .context("Failed to fetch from remote")?;
 
-        let fetch_head = repo
-            .find_reference("FETCH_HEAD")
-            .context("Failed to find FETCH_HEAD")?;
+        let fetch_head_id = outcome
+            .ref_map
+            .mappings
+            .first()
+            .and_then(|mapping| mapping.remote.as_id())
+            .context("Failed to resolve FETCH_HEAD")?
+            .to_owned();
 
-        let fetch_commit = fetch_head
-            .peel(ObjectType::Commit)
+        let fetch_commit = repo
+            .find_object(fetch_head_id)
             .context("Failed to peel FETCH_HEAD to commit")?;
 
-        repo.reset(&fetch_commit, ResetType::Hard, None)
-            .context("Failed to perform hard reset")?;
+        gix::worktree::state::checkout(
+            &repo.to_thread_local(),
+            fetch_commit.id,
+            repo.work_dir().context("Repository has no worktree")?,
+            gix::progress::Discard,
+            gix::progress::Discard,
+            &should_interrupt,
+            Default::default(),
+        )
+        .context("Failed to perform hard reset")?;
 
         info!("✅ Repo pulling finished successfully.");
         Ok(())
@@ -1,6 +1,6 @@
 use crate::crypto::NetworkRecognition;
 use crate::externals::Evaluation;
-use crate::models::{AddressLabel, AdvisoryLevel};
+use crate::models::{AddressLabel, AdvisoryCode, AdvisoryLevel};
 use anyhow::Context;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -50,6 +50,9 @@ impl Evaluation for ScamSniffer {
                     network: address.guess_network(),
                     address: address.to_string(),
                     label: "Known Scammer (Reported by ScamSniffer)".to_string(),
+                    advisory: Some(
+                        AdvisoryCode::PhishingReport.tag("source", "scamsniffer"),
+                    ),
                 },
             ));
         }
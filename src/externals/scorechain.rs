@@ -1,23 +1,45 @@
 use crate::crypto::NetworkRecognition;
 use crate::externals::Evaluation;
-use crate::models::{AddressLabel, AdvisoryLevel};
+use crate::models::{AddressLabel, AdvisoryCode, AdvisoryLevel};
 use anyhow::Result;
 use async_trait::async_trait;
 use dashmap::DashMap;
-use log::info;
+use log::{error, info};
 use reqwest;
+use reqwest::header::{CACHE_CONTROL, ETAG, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
 use serde_json;
+use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+#[derive(Clone)]
 pub struct Dependency {
     pub http_client: reqwest::Client,
 }
 
+/// Default freshness window used when a response carries no
+/// `Cache-Control: max-age` directive.
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Clone)]
+struct CachedResponse {
+    body: serde_json::Value,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    fetched_at: Instant,
+    max_age: Duration,
+}
+
+impl CachedResponse {
+    fn is_fresh(&self) -> bool {
+        self.fetched_at.elapsed() < self.max_age
+    }
+}
+
 pub struct Scorechain {
     dependency: Dependency,
     api_key: String,
-    cached_response: DashMap<String, (serde_json::Value, Instant)>,
+    cached_response: Arc<DashMap<String, CachedResponse>>,
 }
 
 #[async_trait]
@@ -37,6 +59,7 @@ impl Evaluation for Scorechain {
                     network: address.guess_network(),
                     address: address.to_string(),
                     label: name,
+                    advisory: Some(AdvisoryCode::Sanctioned.tag("source", "scorechain")),
                 },
             ));
         }
@@ -50,7 +73,7 @@ impl Scorechain {
         Self {
             dependency: dep,
             api_key: "4117dd88-9dcc-4755-91d4-6510f1bea6a7".to_string(), // It's a free API key, I don't care about leakage 😏
-            cached_response: DashMap::new(),
+            cached_response: Arc::new(DashMap::new()),
         }
     }
 
@@ -72,37 +95,134 @@ impl Scorechain {
             .map(|s| s.to_string())
     }
 
+    /// Conditional-request cache: fresh hits are served from memory, stale
+    /// hits are revalidated with `If-None-Match`/`If-Modified-Since` so a
+    /// `304 Not Modified` avoids re-downloading and re-parsing the body.
+    ///
+    /// A stale entry is served immediately (stale-while-revalidate) while the
+    /// revalidation happens in a spawned task, so a clipboard evaluation
+    /// never blocks on a network round-trip once an address has been seen
+    /// once.
     async fn fetch_or_cache(&self, address: &str) -> serde_json::Value {
-        const TTL: Duration = Duration::from_secs(24 * 60 * 60);
-
         if let Some(entry) = self.cached_response.get(address) {
-            if entry.1.elapsed() < TTL {
-                info!("🗂️ Cache Hit (Valid): {}", address);
-                return entry.value().0.clone();
-            } else {
-                info!("♻️ Cache Hit (Expired): {}", address);
+            if entry.is_fresh() {
+                info!("🗂️ Cache Hit (Fresh): {}", address);
+                return entry.body.clone();
             }
+
+            info!("♻️ Cache Hit (Stale), revalidating: {}", address);
+            let stale_body = entry.body.clone();
+            drop(entry);
+
+            // Stale-while-revalidate: hand back the stale value immediately
+            // and refresh in the background, so the caller (a clipboard
+            // evaluation) never blocks on a network round-trip.
+            tokio::spawn(revalidate(
+                self.dependency.http_client.clone(),
+                self.api_key.clone(),
+                self.cached_response.clone(),
+                address.to_string(),
+            ));
+
+            return stale_body;
         }
 
         info!("⌛️ Fetching Scorechain Sanctions API: {}", address);
-        let url = format!("https://sanctions.api.scorechain.com/v1/addresses/{address}");
-        let response = self
-            .dependency
-            .http_client
-            .get(&url)
-            .header("X-API-Key", &self.api_key)
-            .send()
-            .await
-            .unwrap();
-        let response_json: serde_json::Value = response.json().await.unwrap();
-
-        self.cached_response
-            .insert(address.to_string(), (response_json.clone(), Instant::now()));
+        match fetch_fresh(&self.dependency.http_client, &self.api_key, address, None).await {
+            Ok(cached) => {
+                let body = cached.body.clone();
+                self.cached_response.insert(address.to_string(), cached);
+                body
+            }
+            Err(e) => {
+                error!("Failed to fetch Scorechain Sanctions API for {address}: {e}");
+                serde_json::Value::Null
+            }
+        }
+    }
+}
 
-        response_json
+/// Re-issues the request with the stored validators and refreshes the cache
+/// entry, either bumping its timestamp on `304 Not Modified` or replacing it
+/// on `200 OK`. Takes owned/`Arc`'d parameters so it can run as a detached
+/// `tokio::spawn` task.
+async fn revalidate(
+    http_client: reqwest::Client,
+    api_key: String,
+    cached_response: Arc<DashMap<String, CachedResponse>>,
+    address: String,
+) {
+    let Some(entry) = cached_response.get(&address).map(|e| e.clone()) else {
+        return;
+    };
+
+    match fetch_fresh(&http_client, &api_key, &address, Some(&entry)).await {
+        Ok(cached) => {
+            cached_response.insert(address, cached);
+        }
+        Err(e) => {
+            error!("Failed to revalidate Scorechain cache entry for {address}: {e}");
+        }
     }
 }
 
+async fn fetch_fresh(
+    http_client: &reqwest::Client,
+    api_key: &str,
+    address: &str,
+    existing: Option<&CachedResponse>,
+) -> Result<CachedResponse> {
+    let url = format!("https://sanctions.api.scorechain.com/v1/addresses/{address}");
+    let mut request = http_client.get(&url).header("X-API-Key", api_key);
+
+    if let Some(etag) = existing.and_then(|e| e.etag.as_deref()) {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = existing.and_then(|e| e.last_modified.as_deref()) {
+        request = request.header(IF_MODIFIED_SINCE, last_modified);
+    }
+
+    let response = request.send().await?;
+
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        info!("✅ 304 Not Modified, reusing cached body: {}", address);
+        let existing = existing
+            .ok_or_else(|| anyhow::anyhow!("304 received with no cached entry to refresh"))?;
+        return Ok(CachedResponse {
+            fetched_at: Instant::now(),
+            ..existing.clone()
+        });
+    }
+
+    let etag = header_str(response.headers().get(ETAG)).map(str::to_string);
+    let last_modified = header_str(response.headers().get(LAST_MODIFIED)).map(str::to_string);
+    let max_age = parse_max_age(response.headers().get(CACHE_CONTROL)).unwrap_or(DEFAULT_MAX_AGE);
+
+    let body: serde_json::Value = response.json().await?;
+
+    Ok(CachedResponse {
+        body,
+        etag,
+        last_modified,
+        fetched_at: Instant::now(),
+        max_age,
+    })
+}
+
+fn header_str(value: Option<&HeaderValue>) -> Option<&str> {
+    value.and_then(|v| v.to_str().ok())
+}
+
+fn parse_max_age(value: Option<&HeaderValue>) -> Option<Duration> {
+    let value = header_str(value)?;
+
+    value.split(',').find_map(|directive| {
+        let directive = directive.trim();
+        let seconds = directive.strip_prefix("max-age=")?;
+        seconds.parse::<u64>().ok().map(Duration::from_secs)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +269,15 @@ mod tests {
                 == Some("SUEX OTC, S.R.O. - Successful Exchange (OFAC)".to_string())
         );
     }
+
+    #[test]
+    fn test_parse_max_age() {
+        let header = HeaderValue::from_static("public, max-age=3600");
+        assert_eq!(parse_max_age(Some(&header)), Some(Duration::from_secs(3600)));
+
+        let header = HeaderValue::from_static("no-store");
+        assert_eq!(parse_max_age(Some(&header)), None);
+
+        assert_eq!(parse_max_age(None), None);
+    }
 }
@@ -1,17 +1,24 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod bip353;
 mod clipboard;
 mod crypto;
-mod csv;
 mod externals;
 mod models;
-use crate::models::{AddressLabel, AdvisoryLevel};
+mod poisoning;
+mod registry;
+mod server;
+mod store;
+use crate::models::{ActivityAction, ActivityRecord, AddressLabel, Advisory, AdvisoryLevel, MuteIntent};
+use crate::crypto::detect_network;
 use chrono::{DateTime, Utc};
 use futures::SinkExt;
 use futures::channel::mpsc::Sender;
 use iced::Alignment::Center;
 use iced::widget::{Button, Row, TextInput};
-use iced::widget::{button, column, container, row, svg, text, text_input};
+use iced::widget::{
+    button, column, container, mouse_area, progress_bar, row, scrollable, svg, text, text_input,
+};
 use iced::{
     Background, Border, Color, Element, Length, Padding, Shadow, Size, Subscription, Task, Theme,
     Vector,
@@ -21,16 +28,31 @@ use log::error;
 use log::info;
 use rust_embed::RustEmbed;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::future;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 use tokio::sync::mpsc;
 
 const AUTO_CLOSE_WELCOME_WINDOW_AFTER: chrono::Duration = chrono::Duration::seconds(5);
 const AUTO_CLOSE_PROMPT_WINDOW_AFTER: chrono::Duration = chrono::Duration::seconds(10);
+// How long a button must be held down for `Danger`/`Risky` prompts before
+// its action (closing the window, or applying a persisted `MuteIntent`)
+// takes effect, so a reflexive click can't dismiss a high-risk warning or
+// whitelist a malicious address.
+const HOLD_TO_CONFIRM_DURATION: chrono::Duration = chrono::Duration::milliseconds(2000);
+// How many entries `ActivityWindow` loads from the activity log when opened.
+const ACTIVITY_LOG_DISPLAY_LIMIT: usize = 100;
+
+/// Whether `level` gates closing the window / applying a persisted
+/// `MuteIntent` behind a completed press-and-hold gesture -- and, for the
+/// same reason, is never silently auto-closed by `AUTO_CLOSE_PROMPT_WINDOW_AFTER`.
+fn requires_hold_to_confirm(level: &AdvisoryLevel) -> bool {
+    matches!(level, AdvisoryLevel::Danger | AdvisoryLevel::Risky)
+}
 
 #[derive(RustEmbed)]
 #[folder = "gallery/"]
@@ -41,16 +63,21 @@ struct Gallery;
 // ------------------------------------------------------------------
 #[derive(Default)]
 struct Daemon {
-    csv_context: Arc<Mutex<csv::Context>>,
+    store: Arc<store::Store>,
     welcome_window: Option<(window::Id, WelcomeWindow)>,
-    prompt_window: Option<(window::Id, PromptWindow)>,
+    prompt_windows: HashMap<window::Id, PromptWindow>,
+    activity_window: Option<(window::Id, ActivityWindow)>,
     snooze_until: Option<DateTime<Utc>>,
-    pending_ctx: Option<Value>,
 }
 
 #[derive(Default)]
 struct WelcomeWindow {}
 
+#[derive(Default)]
+struct ActivityWindow {
+    records: Vec<ActivityRecord>,
+}
+
 #[derive(Default)]
 struct PromptWindow {
     ctx: serde_json::Value,
@@ -58,6 +85,13 @@ struct PromptWindow {
     auto_close_window_at: Option<DateTime<Utc>>,
     is_editing: bool,
     user_input: String,
+    /// When the close button's press-and-hold gesture started, for prompts
+    /// that require hold-to-confirm. `None` while the button isn't held.
+    hold_close_started_at: Option<DateTime<Utc>>,
+    /// The persisted `MuteIntent` currently being held (and since when), for
+    /// `Danger`/`Risky` prompts where "Mute Address"/"Mute Network"/"Allow
+    /// Forever" require press-and-hold instead of a single click.
+    hold_mute: Option<(MuteIntent, DateTime<Utc>)>,
 }
 // ------------------------------------------------------------------
 //                               ENUM
@@ -66,11 +100,11 @@ struct PromptWindow {
 enum DaemonMessage {
     // Request to open `WelcomeWindow`
     OpenWelcomeWindow,
-    // Request to open `PromptWindow`
-    OpenPromptWindow,
+    // Request to open a new `PromptWindow` seeded with the given context
+    OpenPromptWindow(Value),
     // Notify that `WelcomeWindow` is opened
     WelcomeWindowOpened(window::Id),
-    // Notify that `PromptWindow` is opened
+    // Notify that a `PromptWindow` is opened
     PromptWindowOpened(window::Id),
     // Auto close `WelcomeWindow` (only triggered by `WelcomeWindowOpened`)
     AutoCloseWelcomeWindow(window::Id),
@@ -80,31 +114,57 @@ enum DaemonMessage {
     WalletAddressDetected(AdvisoryLevel, AddressLabel),
     // Daemon will propagating `WelcomeMessage` to `WelcomeWindow`
     Welcome(WelcomeMessage),
-    // Daemon will propagating `PromptMessage` to `PromptWindow`
-    Prompt(PromptMessage),
+    // Daemon will propagating `PromptMessage` to the `PromptWindow` identified by `window::Id`
+    Prompt(window::Id, PromptMessage),
+    // Request to open `ActivityWindow`, seeded with the activity log entries to display
+    OpenActivityWindow(Vec<ActivityRecord>),
+    // Notify that `ActivityWindow` is opened
+    ActivityWindowOpened(window::Id),
+    // Daemon will propagating `ActivityMessage` to `ActivityWindow`
+    Activity(ActivityMessage),
 }
 
 #[derive(Debug, Clone)]
 enum WelcomeMessage {
     DismissBtnClicked,
+    ViewActivityLogBtnClicked,
+}
+
+#[derive(Debug, Clone)]
+enum ActivityMessage {
+    CloseBtnOnClicked,
 }
 
 #[derive(Debug, Clone)]
 enum PromptMessage {
     Tick(DateTime<Utc>),
-    SetMuteUntil(DateTime<Utc>),
+    Mute(MuteIntent),
     SetContext(serde_json::Value),
     CloseBtnOnClicked,
+    CloseBtnPressed,
+    CloseBtnReleased,
+    MuteBtnPressed(MuteIntent),
+    MuteBtnReleased,
     EditBtnOnClicked,
     SaveBtnOnClicked,
     InputChanged(String),
 }
 
+/// Why a `PromptWindow` requested to close, so the activity log can record
+/// whether the user actually dismissed it or it timed out unattended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CloseReason {
+    UserClosed,
+    AutoClosed,
+}
+
 #[derive(Debug, Clone, Default)]
 enum PromptCallback {
-    RequestToKillSelf,
+    RequestToKillSelf(CloseReason),
     RequestToResizeWindow(Size),
     RequestToSaveContext(serde_json::Value),
+    /// A persisted `MuteIntent`'s press-and-hold gesture completed; apply it.
+    RequestToMute(MuteIntent),
     #[default]
     None,
 }
@@ -175,35 +235,31 @@ impl Daemon {
                 }
             }
             // React to `OpenPromptWindow` actions:
-            //  1. Open `PromptWindow`
+            //  1. Open a new `PromptWindow`, seeded with `ctx`
             //  2. Notify `PromptWindowOpened` and continue next steps
-            DaemonMessage::OpenPromptWindow => {
+            DaemonMessage::OpenPromptWindow(ctx) => {
                 info!("Receive request to open PromptWindow");
-                debug_assert!(self.prompt_window.is_none());
 
-                if self.prompt_window.is_none() {
-                    let (window_id, task) = window::open(default_prompt_window_setting());
-                    self.prompt_window = Some((window_id, PromptWindow::default()));
-                    return task.map(DaemonMessage::PromptWindowOpened);
-                } else {
-                    error!("PromptWindow is already opened!!! (bug exist ⚠️)");
-                }
+                let (window_id, task) = window::open(default_prompt_window_setting());
+                self.prompt_windows.insert(
+                    window_id,
+                    PromptWindow {
+                        ctx,
+                        ..Default::default()
+                    },
+                );
+                return task.map(DaemonMessage::PromptWindowOpened);
             }
             // React to `PromptWindowOpened` actions:
             //  1. Set an auto close window time (determined by `AUTO_CLOSE_PROMPT_WINDOW_AFTER`)
-            //  2. Pass the `pending_prompt_ctx` to `PromptWindow` if exists
-            DaemonMessage::PromptWindowOpened(_window_id) => {
-                debug_assert!(self.prompt_window.is_some());
+            DaemonMessage::PromptWindowOpened(window_id) => {
+                debug_assert!(self.prompt_windows.contains_key(&window_id));
                 info!("PromptWindow has opened");
 
-                self.prompt_window.as_mut().unwrap().1.auto_close_window_at =
-                    Some(Utc::now() + AUTO_CLOSE_PROMPT_WINDOW_AFTER);
-
-                if let Some(pending_ctx) = self.pending_ctx.take() {
-                    if let Some((_, instance)) = &mut self.prompt_window {
-                        info!("Passing pending context to PromptWindow");
-                        instance.update(PromptMessage::SetContext(pending_ctx));
-                    }
+                if let Some(instance) = self.prompt_windows.get_mut(&window_id) {
+                    let now = Utc::now();
+                    instance.current_time = Some(now);
+                    instance.auto_close_window_at = Some(now + AUTO_CLOSE_PROMPT_WINDOW_AFTER);
                 }
             }
             // React to `KillWindow` actions:
@@ -215,9 +271,11 @@ impl Daemon {
             }
             // React to `WalletAddressDetected` actions:
             //  1. Early return if app is snoozing. (e.g. do nothing)
-            //  2. Serialize the input args into JSON context.
-            //  3. Request to `OpenPromptWindow`
-            //  3a. If the `PromptWindow` already exists, UPDATE its context.
+            //  2. Early return if a persisted `MuteIntent` suppresses this address/network.
+            //  3. Serialize the input args into JSON context.
+            //  4. If a `PromptWindow` already exists for this `(network, address)`, UPDATE its
+            //      context instead of opening a duplicate.
+            //  5. Otherwise request to `OpenPromptWindow`.
             //
             // Note:
             //  All the contexts that are going to be dispatched to `PromptWindow`
@@ -235,96 +293,279 @@ impl Daemon {
                     }
                 }
 
-                // Serialize input args into JSON context
-                let ctx = serde_json::to_value((level, label)).unwrap_or(serde_json::Value::Null);
+                // Check persisted mute/allow rules
+                match self.store.is_muted(&label.network, &label.address) {
+                    Ok(true) => {
+                        info!("{:?} is muted by a persisted MuteIntent", label.address);
+                        return Task::none();
+                    }
+                    Ok(false) => {}
+                    Err(e) => error!("Failed to check mute rules: {e}"),
+                }
 
-                // If the `PromptWindow` exists, update its context.
-                // If the `PromptWindow` doesn't exist, store the `ctx` in `pending_prompt_ctx`,
-                //  and request to `OpenPromptWindow` to proceeding `pending_prompt_ctx`.
-                if let Some((_, instance)) = &mut self.prompt_window {
-                    instance.update(PromptMessage::SetContext(ctx));
+                // Serialize input args into JSON context
+                let ctx = serde_json::to_value((level, label.clone())).unwrap_or(serde_json::Value::Null);
+
+                // If a `PromptWindow` already tracks this address, update its context in place.
+                // Otherwise, open a new `PromptWindow` seeded with `ctx`.
+                let has_existing_window = self
+                    .prompt_windows
+                    .values()
+                    .any(|instance| instance.shows_address(&label));
+
+                if has_existing_window {
+                    return self.emit_filter(
+                        |instance| instance.shows_address(&label),
+                        PromptMessage::SetContext(ctx),
+                    );
                 } else {
-                    self.pending_ctx = Some(ctx);
-                    return Task::done(DaemonMessage::OpenPromptWindow);
+                    return Task::done(DaemonMessage::OpenPromptWindow(ctx));
                 }
             }
 
             // React to `Welcome` actions:
-            //  1. Handle `WelcomeMessage` that need to be propagated to `WelcomeWindow`
+            //  Route the `WelcomeMessage` to `WelcomeWindow` via `emit_to_welcome`.
             DaemonMessage::Welcome(msg) => {
-                if let Some((window_id, _instance)) = &mut self.welcome_window {
-                    match msg {
-                        // React to `WelcomeMessage::DismissBtnClicked` actions:
-                        //  1. Kill the `WelcomeWindow`
-                        WelcomeMessage::DismissBtnClicked => {
-                            info!("DismissBtnClicked => Kill WelcomeWindow");
-                            return Task::done(DaemonMessage::KillWindow(*window_id));
-                        }
-                    }
-                }
+                return self.emit_to_welcome(msg);
             }
 
             // React to `Prompt` actions:
-            //  1. Handle `PromptMessage` that doesn't need to be propagated.
-            //  2. Propagate `PromptMessage` to `PromptWindow`.
-            //  3. Handle callback action from `PromptWindow`.
-            DaemonMessage::Prompt(msg) => {
-                if let Some((window_id, instance)) = &mut self.prompt_window {
-                    // Handle `PromptMessage` that doesn't need to be propagated
-                    match msg {
-                        // React to `PromptMessage::SetMuteUntil` actions:
-                        //  1. Set the `snooze_until` to activate snoozing mode
-                        //  2. Kill the `PromptWindow`
-                        PromptMessage::SetMuteUntil(deadline) => {
-                            info!("Set snooze until {}", deadline);
-                            self.snooze_until = Some(deadline);
-                            return Task::done(DaemonMessage::KillWindow(*window_id));
-                        }
-                        _ => {}
-                    }
+            //  Route the `PromptMessage` to the targeted `PromptWindow` via `emit_to`.
+            DaemonMessage::Prompt(window_id, msg) => {
+                return self.emit_to(window_id, msg);
+            }
 
-                    // Propagate `PromptMessage` to `PromptWindow`.
-                    let callback = instance.update(msg);
+            // React to `OpenActivityWindow` actions:
+            //  1. Open a new `ActivityWindow`, seeded with the given records
+            //  2. Notify `ActivityWindowOpened`
+            DaemonMessage::OpenActivityWindow(records) => {
+                info!("Receive request to open ActivityWindow");
+                if self.activity_window.is_none() {
+                    let (window_id, task) = window::open(default_activity_window_setting());
+                    self.activity_window = Some((window_id, ActivityWindow { records }));
+                    return task.map(DaemonMessage::ActivityWindowOpened);
+                } else {
+                    error!("ActivityWindow is already opened!!! (bug exist ⚠️)");
+                }
+            }
+            // React to `ActivityWindowOpened` actions: nothing further to do, the window
+            // was already seeded with its records when it was opened.
+            DaemonMessage::ActivityWindowOpened(_window_id) => {
+                debug_assert!(self.activity_window.is_some());
+                info!("ActivityWindow has opened");
+            }
 
-                    // Handle callback action from `PromptWindow`
-                    match callback {
-                        PromptCallback::RequestToKillSelf => {
-                            info!("PromptWindow requested to kill itself (callback)");
-                            return Task::done(DaemonMessage::KillWindow(*window_id));
-                        }
-                        PromptCallback::RequestToResizeWindow(size) => {
-                            info!("PromptWindow requested to resize (callback)");
-                            return window::resize(*window_id, size);
-                        }
-                        PromptCallback::RequestToSaveContext(ctx) => {
-                            info!("PromptWindow requested to save context (callback)");
-                            let ctx_deserialized: Result<(AdvisoryLevel, AddressLabel), _> =
-                                serde_json::from_value(ctx);
-
-                            if let Ok((_, address_label)) = ctx_deserialized {
-                                let new_entry = AddressLabel {
-                                    network: address_label.network,
-                                    address: address_label.address,
-                                    label: std::mem::take(&mut instance.user_input),
-                                };
-
-                                if let Err(e) = self.csv_context.lock().unwrap().append(new_entry) {
-                                    error!("Failed to append csv: {e}");
-                                } else {
-                                    info!("Successfully appended csv");
-                                }
-                            }
-                            return Task::done(DaemonMessage::KillWindow(*window_id));
-                        }
-                        PromptCallback::None => {
-                            return Task::none();
-                        }
+            // React to `Activity` actions:
+            //  Route the `ActivityMessage` to `ActivityWindow` via `emit_to_activity`.
+            DaemonMessage::Activity(msg) => {
+                return self.emit_to_activity(msg);
+            }
+        }
+
+        default_return
+    }
+
+    /// Dispatches `msg` to the `WelcomeWindow`, if one is open. The single
+    /// code path `update` uses to talk to the `WelcomeWindow`, mirroring
+    /// `emit_to`/`emit_to_activity` for the other window types.
+    fn emit_to_welcome(&mut self, msg: WelcomeMessage) -> Task<DaemonMessage> {
+        let Some((window_id, _instance)) = &self.welcome_window else {
+            return Task::none();
+        };
+        let window_id = *window_id;
+
+        match msg {
+            // React to `WelcomeMessage::DismissBtnClicked` actions:
+            //  1. Kill the `WelcomeWindow`
+            WelcomeMessage::DismissBtnClicked => {
+                info!("DismissBtnClicked => Kill WelcomeWindow");
+                Task::done(DaemonMessage::KillWindow(window_id))
+            }
+            // React to `WelcomeMessage::ViewActivityLogBtnClicked` actions:
+            //  1. Load recent activity log entries from the store
+            //  2. Request to `OpenActivityWindow`, seeded with them
+            WelcomeMessage::ViewActivityLogBtnClicked => {
+                info!("ViewActivityLogBtnClicked => open ActivityWindow");
+                let records = self
+                    .store
+                    .recent_activity(ACTIVITY_LOG_DISPLAY_LIMIT)
+                    .unwrap_or_else(|e| {
+                        error!("Failed to read activity log: {e}");
+                        Vec::new()
+                    });
+                Task::done(DaemonMessage::OpenActivityWindow(records))
+            }
+        }
+    }
+
+    /// Dispatches `msg` to the `ActivityWindow`, if one is open. The single
+    /// code path `update` uses to talk to the `ActivityWindow`, mirroring
+    /// `emit_to`/`emit_to_welcome` for the other window types.
+    fn emit_to_activity(&mut self, msg: ActivityMessage) -> Task<DaemonMessage> {
+        let Some((window_id, _instance)) = &self.activity_window else {
+            return Task::none();
+        };
+        let window_id = *window_id;
+
+        match msg {
+            ActivityMessage::CloseBtnOnClicked => {
+                info!("ActivityWindow Close button on clicked");
+                Task::done(DaemonMessage::KillWindow(window_id))
+            }
+        }
+    }
+
+    /// Dispatches `msg` to the `PromptWindow` identified by `window_id`: handles
+    /// the subset of `PromptMessage` that the `Daemon` itself needs to react to,
+    /// propagates the rest to the window, and turns the resulting `PromptCallback`
+    /// into a `Task`. The single code path `update` uses to talk to a `PromptWindow`,
+    /// mirroring `emit_to_welcome`/`emit_to_activity` for the other window types.
+    fn emit_to(&mut self, window_id: window::Id, msg: PromptMessage) -> Task<DaemonMessage> {
+        let Some(instance) = self.prompt_windows.get_mut(&window_id) else {
+            return Task::none();
+        };
+
+        // React to `PromptMessage::Mute` actions:
+        //  1. Apply the `MuteIntent` (snooze in memory, or persist to the label store)
+        //  2. Record the resolution in the activity log
+        //  3. Kill the `PromptWindow`
+        if let PromptMessage::Mute(intent) = msg {
+            let ctx_deserialized: Result<(AdvisoryLevel, AddressLabel), _> =
+                serde_json::from_value(instance.ctx.clone());
+
+            self.apply_mute_intent(intent);
+
+            if let Ok((advisory_level, address_label)) = ctx_deserialized {
+                self.log_prompt_activity(&address_label, advisory_level, ActivityAction::Muted);
+            }
+
+            return Task::done(DaemonMessage::KillWindow(window_id));
+        }
+
+        // Propagate `PromptMessage` to `PromptWindow`.
+        let callback = instance.update(msg);
+
+        // Handle callback action from `PromptWindow`
+        match callback {
+            PromptCallback::RequestToKillSelf(reason) => {
+                info!("PromptWindow requested to kill itself (callback)");
+                let ctx_deserialized: Result<(AdvisoryLevel, AddressLabel), _> =
+                    serde_json::from_value(instance.ctx.clone());
+
+                if let Ok((advisory_level, address_label)) = ctx_deserialized {
+                    let action = match reason {
+                        CloseReason::UserClosed => ActivityAction::Closed,
+                        CloseReason::AutoClosed => ActivityAction::AutoClosed,
+                    };
+                    self.log_prompt_activity(&address_label, advisory_level, action);
+                }
+
+                Task::done(DaemonMessage::KillWindow(window_id))
+            }
+            PromptCallback::RequestToResizeWindow(size) => {
+                info!("PromptWindow requested to resize (callback)");
+                window::resize(window_id, size)
+            }
+            PromptCallback::RequestToSaveContext(ctx) => {
+                info!("PromptWindow requested to save context (callback)");
+                let ctx_deserialized: Result<(AdvisoryLevel, AddressLabel), _> =
+                    serde_json::from_value(ctx);
+
+                if let Ok((advisory_level, address_label)) = ctx_deserialized {
+                    let new_entry = AddressLabel {
+                        network: address_label.network,
+                        address: address_label.address,
+                        label: std::mem::take(&mut instance.user_input),
+                        advisory: None,
+                    };
+
+                    self.log_prompt_activity(&new_entry, advisory_level, ActivityAction::LabelSaved);
+
+                    if let Err(e) = self.store.upsert(new_entry) {
+                        error!("Failed to upsert label store entry: {e}");
+                    } else {
+                        info!("Successfully upserted label store entry");
                     }
                 }
+                Task::done(DaemonMessage::KillWindow(window_id))
             }
+            PromptCallback::RequestToMute(intent) => {
+                info!("PromptWindow requested to mute (callback, hold-to-confirm completed)");
+                let ctx_deserialized: Result<(AdvisoryLevel, AddressLabel), _> =
+                    serde_json::from_value(instance.ctx.clone());
+
+                self.apply_mute_intent(intent);
+
+                if let Ok((advisory_level, address_label)) = ctx_deserialized {
+                    self.log_prompt_activity(&address_label, advisory_level, ActivityAction::Muted);
+                }
+
+                Task::done(DaemonMessage::KillWindow(window_id))
+            }
+            PromptCallback::None => Task::none(),
         }
+    }
 
-        default_return
+    /// Applies a `MuteIntent`: keeps `SnoozeAll` as in-memory-only state,
+    /// persists every other variant to the label store.
+    fn apply_mute_intent(&mut self, intent: MuteIntent) {
+        match intent {
+            MuteIntent::SnoozeAll(duration) => {
+                let deadline = Utc::now()
+                    + chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::zero());
+                info!("Snoozing all detections until {}", deadline);
+                self.snooze_until = Some(deadline);
+            }
+            persisted_intent => {
+                info!("Persisting mute intent: {:?}", persisted_intent);
+                if let Err(e) = self.store.mute(persisted_intent) {
+                    error!("Failed to persist mute intent: {e}");
+                }
+            }
+        }
+    }
+
+    /// Appends one entry to the activity log for how a `PromptWindow`
+    /// showing `address_label` was resolved.
+    fn log_prompt_activity(
+        &self,
+        address_label: &AddressLabel,
+        advisory_level: AdvisoryLevel,
+        action: ActivityAction,
+    ) {
+        let record = ActivityRecord {
+            timestamp: Utc::now(),
+            network: address_label.network,
+            address: address_label.address.clone(),
+            advisory_level,
+            label: address_label.label.clone(),
+            action,
+        };
+
+        if let Err(e) = self.store.log_activity(record) {
+            error!("Failed to append activity log entry: {e}");
+        }
+    }
+
+    /// Dispatches `msg` to every `PromptWindow` matching `predicate` (e.g. "all
+    /// prompt windows showing a sanctioned address"), batching the resulting `Task`s.
+    fn emit_filter(
+        &mut self,
+        predicate: impl Fn(&PromptWindow) -> bool,
+        msg: PromptMessage,
+    ) -> Task<DaemonMessage> {
+        let matching_window_ids: Vec<window::Id> = self
+            .prompt_windows
+            .iter()
+            .filter(|(_, instance)| predicate(instance))
+            .map(|(&window_id, _)| window_id)
+            .collect();
+
+        Task::batch(
+            matching_window_ids
+                .into_iter()
+                .map(|window_id| self.emit_to(window_id, msg.clone())),
+        )
     }
 
     fn view(&self, wid: window::Id) -> Element<'_, DaemonMessage> {
@@ -334,9 +575,13 @@ impl Daemon {
             }
         }
 
-        if let Some((id, state)) = &self.prompt_window {
+        if let Some(state) = self.prompt_windows.get(&wid) {
+            return state.view(wid).map(move |msg| DaemonMessage::Prompt(wid, msg));
+        }
+
+        if let Some((id, state)) = &self.activity_window {
             if *id == wid {
-                return state.view(wid).map(DaemonMessage::Prompt);
+                return state.view().map(DaemonMessage::Activity);
             }
         }
 
@@ -349,9 +594,9 @@ impl Daemon {
     }
 
     fn subscribe(&self) -> Subscription<DaemonMessage> {
-        struct HashableCsvContext(Arc<Mutex<csv::Context>>); // just a wrapper of `self.csv_context`
+        struct HashableStore(Arc<store::Store>); // just a wrapper of `self.store`
 
-        impl Hash for HashableCsvContext {
+        impl Hash for HashableStore {
             fn hash<H: Hasher>(&self, state: &mut H) {
                 Arc::as_ptr(&self.0).hash(state);
             }
@@ -361,17 +606,21 @@ impl Daemon {
             Subscription::none() // waiting for `WelcomeWindow` is gone
         } else {
             Subscription::run_with(
-                HashableCsvContext(self.csv_context.clone()),
-                |hashable_csv_context| {
-                    // unwrap to get the origin `self.csv_context`
-                    let csv_context = hashable_csv_context.0.clone();
+                HashableStore(self.store.clone()),
+                |hashable_store| {
+                    // unwrap to get the origin `self.store`
+                    let store = hashable_store.0.clone();
 
                     stream::channel(100, |mut output: Sender<DaemonMessage>| async move {
                         let (tx, mut rx) = mpsc::unbounded_channel();
+                        let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel();
 
-                        thread::spawn(move || {
-                            crate::clipboard::start_listening(csv_context, tx);
-                        });
+                        // Lives on the main runtime (no nested `Runtime`). If
+                        // iced drops this subscription (e.g. the hash above
+                        // changes), `cancel_tx` is dropped along with it,
+                        // which fires `cancel_rx` and stops the watcher
+                        // without waiting out its poll interval.
+                        tokio::spawn(crate::clipboard::start_listening(store, tx, cancel_rx));
 
                         while let Some((level, label)) = rx.recv().await {
                             let _ = output
@@ -379,20 +628,25 @@ impl Daemon {
                                 .await;
                         }
 
+                        let _keep_alive = cancel_tx;
                         future::pending().await
                     })
                 },
             )
         };
 
-        let ticker = if self.prompt_window.is_some() {
+        // One keyed ticker per open `PromptWindow`, so each window's
+        // `auto_close_window_at` countdown runs independently instead of
+        // sharing a single subscription across every prompt.
+        let prompt_tickers = Subscription::batch(self.prompt_windows.keys().map(|&window_id| {
             iced::time::every(Duration::from_secs_f32(0.01))
-                .map(|_| DaemonMessage::Prompt(PromptMessage::Tick(Utc::now())))
-        } else {
-            Subscription::none()
-        };
+                .with(window_id)
+                .map(|(window_id, _instant)| {
+                    DaemonMessage::Prompt(window_id, PromptMessage::Tick(Utc::now()))
+                })
+        }));
 
-        Subscription::batch(vec![clipboard_monitoring, ticker])
+        Subscription::batch(vec![clipboard_monitoring, prompt_tickers])
     }
 
     /// A helper method to clean up the state of `self`
@@ -404,9 +658,11 @@ impl Daemon {
             }
         }
 
-        if let Some((window_id, _)) = self.prompt_window {
+        self.prompt_windows.remove(&target_window_id);
+
+        if let Some((window_id, _)) = self.activity_window {
             if target_window_id == window_id {
-                self.prompt_window = None;
+                self.activity_window = None;
             }
         }
     }
@@ -429,10 +685,17 @@ impl WelcomeWindow {
                 ]
                 .spacing(10)
                 .align_x(Center),
-                button(text("Close Window").center())
-                    .padding(12)
-                    .style(button::secondary)
-                    .on_press(WelcomeMessage::DismissBtnClicked)
+                row![
+                    button(text("View Activity Log").center())
+                        .padding(12)
+                        .style(button::secondary)
+                        .on_press(WelcomeMessage::ViewActivityLogBtnClicked),
+                    button(text("Close Window").center())
+                        .padding(12)
+                        .style(button::secondary)
+                        .on_press(WelcomeMessage::DismissBtnClicked),
+                ]
+                .spacing(10),
             ]
             .spacing(20)
             .align_x(Center),
@@ -462,28 +725,66 @@ impl WelcomeWindow {
 }
 
 impl PromptWindow {
+    /// Whether this window's context is currently showing `label`'s `(network, address)`.
+    fn shows_address(&self, label: &AddressLabel) -> bool {
+        let ctx_deserialized: Result<(AdvisoryLevel, AddressLabel), _> =
+            serde_json::from_value(self.ctx.clone());
+
+        ctx_deserialized
+            .map(|(_, existing_label)| {
+                existing_label.network == label.network && existing_label.address == label.address
+            })
+            .unwrap_or(false)
+    }
+
     fn update(&mut self, message: PromptMessage) -> PromptCallback {
         match message {
             PromptMessage::Tick(now) => {
                 self.current_time = Some(now);
 
+                if let Some(started_at) = self.hold_close_started_at {
+                    if now - started_at >= HOLD_TO_CONFIRM_DURATION {
+                        info!("PromptWindow close hold-to-confirm completed");
+                        self.hold_close_started_at = None;
+                        self.auto_close_window_at = None;
+                        return PromptCallback::RequestToKillSelf(CloseReason::UserClosed);
+                    }
+                }
+
+                if let Some((intent, started_at)) = &self.hold_mute {
+                    if now - *started_at >= HOLD_TO_CONFIRM_DURATION {
+                        info!("PromptWindow mute hold-to-confirm completed");
+                        let intent = intent.clone();
+                        self.hold_mute = None;
+                        self.auto_close_window_at = None;
+                        return PromptCallback::RequestToMute(intent);
+                    }
+                }
+
                 if let Some(deadline) = self.auto_close_window_at {
                     let ctx_deserialized_result: Result<(AdvisoryLevel, AddressLabel), _> =
                         serde_json::from_value(self.ctx.clone());
 
                     if let Ok((advisory_level, _)) = ctx_deserialized_result {
-                        if advisory_level == AdvisoryLevel::Unknown {
+                        // `Unknown` is still awaiting an evaluation result, and
+                        // `Danger`/`Risky` require an explicit completed
+                        // press-and-hold to dismiss -- neither should silently
+                        // auto-close just because the user didn't touch the
+                        // window.
+                        if advisory_level == AdvisoryLevel::Unknown
+                            || requires_hold_to_confirm(&advisory_level)
+                        {
                             return PromptCallback::None;
                         }
                     }
 
                     if now >= deadline {
                         info!("PromptWindow auto close times out");
-                        return PromptCallback::RequestToKillSelf;
+                        return PromptCallback::RequestToKillSelf(CloseReason::AutoClosed);
                     }
                 }
             }
-            PromptMessage::SetMuteUntil(_) => { /* state is updated in the parent `Daemon` */ }
+            PromptMessage::Mute(_) => { /* state is updated in the parent `Daemon` */ }
             PromptMessage::SetContext(value) => {
                 self.current_time = Some(Utc::now());
                 self.auto_close_window_at = Some(Utc::now() + AUTO_CLOSE_PROMPT_WINDOW_AFTER);
@@ -509,7 +810,25 @@ impl PromptWindow {
                 info!("PromptWindow Close button on clicked");
                 self.is_editing = false;
                 self.auto_close_window_at = None;
-                return PromptCallback::RequestToKillSelf;
+                return PromptCallback::RequestToKillSelf(CloseReason::UserClosed);
+            }
+            PromptMessage::CloseBtnPressed => {
+                info!("PromptWindow Close button press-and-hold started");
+                self.hold_close_started_at = Some(self.current_time.unwrap_or_else(Utc::now));
+            }
+            PromptMessage::CloseBtnReleased => {
+                if self.hold_close_started_at.take().is_some() {
+                    info!("PromptWindow Close button released early, cancelling hold");
+                }
+            }
+            PromptMessage::MuteBtnPressed(intent) => {
+                info!("PromptWindow mute button press-and-hold started");
+                self.hold_mute = Some((intent, self.current_time.unwrap_or_else(Utc::now)));
+            }
+            PromptMessage::MuteBtnReleased => {
+                if self.hold_mute.take().is_some() {
+                    info!("PromptWindow mute button released early, cancelling hold");
+                }
             }
         }
         PromptCallback::None
@@ -529,7 +848,7 @@ impl PromptWindow {
                     let mut info_column = column![
                         self.wallet_address_row(&address_label),
                         self.wallet_label_row(&address_label),
-                        self.wallet_risk_row(&advisory_level),
+                        self.wallet_risk_row(&advisory_level, address_label.advisory.as_ref()),
                     ]
                     .spacing(8);
 
@@ -565,14 +884,13 @@ impl PromptWindow {
             .style(container::rounded_box);
 
         let button_row = {
-            let close_btn = self.close_button();
-            let edit_or_save_btn = self.edit_or_save_button();
-            let mute_for_10_mins_btn = self.mute_button(
-                "Mute for 10 mins",
-                PromptMessage::SetMuteUntil(Utc::now() + chrono::Duration::minutes(10)),
-            );
+            let mut row = row![self.close_button(), self.edit_or_save_button()].spacing(10);
+
+            for mute_btn in self.mute_intent_buttons() {
+                row = row.push(mute_btn);
+            }
 
-            row![close_btn, edit_or_save_btn, mute_for_10_mins_btn,].spacing(10)
+            row
         };
 
         column![title_row, information_box, button_row]
@@ -596,15 +914,30 @@ impl PromptWindow {
     }
 
     fn wallet_address_row(&self, address_label: &AddressLabel) -> Row<'_, PromptMessage> {
-        let network_icon_file = Gallery::get(format!("{:?}.svg", address_label.network).as_str())
+        let (detected_network, is_valid_format) = detect_network(&address_label.address);
+
+        let network_icon_file = Gallery::get(format!("{:?}.svg", detected_network).as_str())
             .unwrap_or(Gallery::get("Other.svg").unwrap());
         let network_icon_handle = svg::Handle::from_memory(network_icon_file.data.into_owned());
         let network_icon = svg::<Theme>(network_icon_handle).width(20).height(20);
         let wallet_address = text(address_label.address.clone()).size(20);
 
-        row![network_icon, wallet_address,]
+        let mut address_row = row![network_icon, wallet_address]
             .spacing(5)
-            .align_y(Center)
+            .align_y(Center);
+
+        // The address itself disagreeing with the caller-supplied network
+        // (or failing its own checksum) is a sign it was tampered with or
+        // mislabeled -- surface it instead of silently trusting `address_label.network`.
+        if detected_network != address_label.network || !is_valid_format {
+            address_row = address_row.push(
+                text("⚠️ Network/format mismatch")
+                    .size(14)
+                    .color(Color::from_str("#CC0000").unwrap()),
+            );
+        }
+
+        address_row
     }
 
     fn wallet_label_row(&self, address_label: &AddressLabel) -> Row<'_, PromptMessage> {
@@ -627,32 +960,12 @@ impl PromptWindow {
         row![wallet_label_text]
     }
 
-    fn wallet_risk_row(&self, advisory_level: &AdvisoryLevel) -> Row<'_, PromptMessage> {
-        let risk_tag_bg_color = match advisory_level {
-            AdvisoryLevel::Unknown => Color::from_str("#F0F0F0").unwrap(),
-            AdvisoryLevel::Known => Color::from_str("#F0F0F0").unwrap(),
-            AdvisoryLevel::Warning => Color::from_str("#FFD700").unwrap(),
-            AdvisoryLevel::Risky => Color::from_str("#FFA500").unwrap(),
-            AdvisoryLevel::Danger => Color::from_str("#FF4500").unwrap(),
-        };
-
-        let risk_level_title = text("Risk Level:").size(15).style(text::secondary);
-
-        let risk_level_tag = container(
-            text(format!("{:?}", advisory_level))
-                .size(15)
-                .color(Color::BLACK),
-        )
-        .padding([4, 8])
-        .style(move |_theme| container::Style {
-            background: Some(Background::Color(risk_tag_bg_color)),
-            border: border::rounded(4),
-            ..Default::default()
-        });
-
-        row![risk_level_title, risk_level_tag]
-            .spacing(10)
-            .align_y(Center)
+    fn wallet_risk_row(
+        &self,
+        advisory_level: &AdvisoryLevel,
+        advisory: Option<&Advisory>,
+    ) -> Row<'_, PromptMessage> {
+        risk_level_row(advisory_level, advisory)
     }
 
     fn custom_label_text_input(&self) -> TextInput<'_, PromptMessage> {
@@ -668,7 +981,22 @@ impl PromptWindow {
     //                       COMPONENTS - BUTTONS
     // ------------------------------------------------------------------
 
-    fn close_button(&self) -> Button<'_, PromptMessage> {
+    fn close_button(&self) -> Element<'_, PromptMessage> {
+        let ctx_deserialized_result: Result<(AdvisoryLevel, AddressLabel), _> =
+            serde_json::from_value(self.ctx.clone());
+        let advisory_level = ctx_deserialized_result.ok().map(|(level, _)| level);
+
+        // Danger/Risky prompts require press-and-hold instead of a single
+        // click, so a reflexive click can't dismiss a high-risk warning.
+        // Cancelling an in-progress edit is exempt: it isn't dismissing the
+        // warning, just backing out of the label text field.
+        let requires_hold =
+            !self.is_editing && advisory_level.as_ref().is_some_and(requires_hold_to_confirm);
+
+        if requires_hold {
+            return self.hold_to_close_button();
+        }
+
         let auto_close_remaining_seconds_text =
             if let (Some(deadline), Some(now)) = (self.auto_close_window_at, self.current_time) {
                 let diff = deadline - now;
@@ -684,17 +1012,9 @@ impl PromptWindow {
         let display_text = if self.is_editing {
             "Cancel".to_string()
         } else {
-            let ctx_deserialized_result: Result<(AdvisoryLevel, AddressLabel), _> =
-                serde_json::from_value(self.ctx.clone());
-
-            if let Ok((advisory_level, _address_label)) = ctx_deserialized_result {
-                if advisory_level == AdvisoryLevel::Unknown {
-                    "Close".to_string()
-                } else {
-                    format!("Close({})", auto_close_remaining_seconds_text)
-                }
-            } else {
-                "Close".to_string()
+            match advisory_level {
+                Some(AdvisoryLevel::Unknown) | None => "Close".to_string(),
+                Some(_) => format!("Close({})", auto_close_remaining_seconds_text),
             }
         };
 
@@ -702,6 +1022,88 @@ impl PromptWindow {
             .width(Length::Fill)
             .on_press(PromptMessage::CloseBtnOnClicked)
             .style(button::danger)
+            .into()
+    }
+
+    /// Press-and-hold close gesture for `Danger`/`Risky` prompts: tracks
+    /// `hold_close_started_at` via `CloseBtnPressed`/`CloseBtnReleased` and
+    /// renders its progress as a filling bar; `Tick` is what actually
+    /// triggers the close once the hold completes (see `update`).
+    fn hold_to_close_button(&self) -> Element<'_, PromptMessage> {
+        self.hold_to_confirm_widget(
+            self.hold_close_started_at,
+            "Hold to Close…",
+            "Press & Hold to Close",
+            PromptMessage::CloseBtnPressed,
+            PromptMessage::CloseBtnReleased,
+        )
+    }
+
+    /// Press-and-hold confirm gesture for a persisted `MuteIntent` on
+    /// `Danger`/`Risky` prompts: same rendering and `Tick`-driven completion
+    /// as `hold_to_close_button`, but fires `MuteBtnPressed`/`MuteBtnReleased`
+    /// and only shows its progress while `intent` itself (not some other
+    /// mute button) is the one being held.
+    fn hold_to_mute_button(&self, idle_label: &str, intent: MuteIntent) -> Element<'_, PromptMessage> {
+        let held_since = self
+            .hold_mute
+            .as_ref()
+            .filter(|(held_intent, _)| held_intent == &intent)
+            .map(|(_, started_at)| *started_at);
+
+        self.hold_to_confirm_widget(
+            held_since,
+            "Hold to Confirm…",
+            idle_label,
+            PromptMessage::MuteBtnPressed(intent),
+            PromptMessage::MuteBtnReleased,
+        )
+    }
+
+    /// Shared press-and-hold rendering for `hold_to_close_button` and
+    /// `hold_to_mute_button`: a filling progress bar tracking `held_since`
+    /// against `HOLD_TO_CONFIRM_DURATION`, firing `on_press`/`on_release` on
+    /// press/release. The caller's `Tick` handler is what actually applies
+    /// the action once the hold completes.
+    fn hold_to_confirm_widget<'a>(
+        &self,
+        held_since: Option<DateTime<Utc>>,
+        holding_label: &'a str,
+        idle_label: &'a str,
+        on_press: PromptMessage,
+        on_release: PromptMessage,
+    ) -> Element<'a, PromptMessage> {
+        let progress = held_since
+            .zip(self.current_time)
+            .map(|(started_at, now)| {
+                let elapsed_ms = (now - started_at).num_milliseconds().max(0) as f32;
+                let duration_ms = HOLD_TO_CONFIRM_DURATION.num_milliseconds() as f32;
+                (elapsed_ms / duration_ms * 100.0).min(100.0)
+            })
+            .unwrap_or(0.0);
+
+        let label_text = if held_since.is_some() { holding_label } else { idle_label };
+
+        let content = container(
+            column![
+                text(label_text).center().size(15).color(Color::WHITE),
+                progress_bar(0.0..=100.0, progress).height(6),
+            ]
+            .spacing(6)
+            .width(Length::Fill),
+        )
+        .padding(10)
+        .width(Length::Fill)
+        .style(|_theme| container::Style {
+            background: Some(Background::Color(Color::from_str("#FF4500").unwrap())),
+            border: border::rounded(4),
+            ..Default::default()
+        });
+
+        mouse_area(content)
+            .on_press(on_press)
+            .on_release(on_release)
+            .into()
     }
 
     /// Determine which button to display based on the current state (either "Edit" or "Save")
@@ -734,6 +1136,173 @@ impl PromptWindow {
             .on_press(msg)
             .width(Length::Fill)
     }
+
+    /// One button per `MuteIntent` a user can apply from this `PromptWindow`:
+    /// a temporary snooze, plus (once the address is known) the persisted
+    /// address/network mutes and the permanent allow rule. On `Danger`/`Risky`
+    /// prompts the persisted mutes require press-and-hold instead of a single
+    /// click, same as `close_button`, so a reflexive click can't permanently
+    /// whitelist a malicious address.
+    fn mute_intent_buttons(&self) -> Vec<Element<'_, PromptMessage>> {
+        let mut buttons: Vec<Element<'_, PromptMessage>> = vec![
+            self.mute_button(
+                "Snooze 10 mins",
+                PromptMessage::Mute(MuteIntent::SnoozeAll(Duration::from_secs(10 * 60))),
+            )
+            .into(),
+        ];
+
+        let ctx_deserialized_result: Result<(AdvisoryLevel, AddressLabel), _> =
+            serde_json::from_value(self.ctx.clone());
+
+        if let Ok((advisory_level, address_label)) = ctx_deserialized_result {
+            let requires_hold = requires_hold_to_confirm(&advisory_level);
+
+            let persisted_intents = [
+                (
+                    "Mute Address",
+                    MuteIntent::MuteAddress {
+                        network: address_label.network,
+                        address: address_label.address.clone(),
+                    },
+                ),
+                (
+                    "Mute Network",
+                    MuteIntent::MuteNetwork(address_label.network),
+                ),
+                (
+                    "Allow Forever",
+                    MuteIntent::AllowAddressForever {
+                        network: address_label.network,
+                        address: address_label.address,
+                    },
+                ),
+            ];
+
+            for (label, intent) in persisted_intents {
+                buttons.push(if requires_hold {
+                    self.hold_to_mute_button(label, intent)
+                } else {
+                    self.mute_button(label, PromptMessage::Mute(intent)).into()
+                });
+            }
+        }
+
+        buttons
+    }
+}
+
+impl ActivityWindow {
+    fn view(&self) -> Element<'_, ActivityMessage> {
+        let title = text("Activity Log").size(26);
+
+        let entries: Element<'_, ActivityMessage> = if self.records.is_empty() {
+            text("No activity recorded yet.").size(16).into()
+        } else {
+            let mut list = column![].spacing(10);
+            for record in &self.records {
+                list = list.push(self.activity_row(record));
+            }
+            scrollable(list).height(Length::Fill).into()
+        };
+
+        let close_button = button(text("Close").center())
+            .width(Length::Fill)
+            .on_press(ActivityMessage::CloseBtnOnClicked)
+            .style(button::secondary);
+
+        column![title, entries, close_button]
+            .spacing(15)
+            .padding(20)
+            .into()
+    }
+
+    fn activity_row(&self, record: &ActivityRecord) -> Element<'_, ActivityMessage> {
+        let network_icon_file = Gallery::get(format!("{:?}.svg", record.network).as_str())
+            .unwrap_or(Gallery::get("Other.svg").unwrap());
+        let network_icon_handle = svg::Handle::from_memory(network_icon_file.data.into_owned());
+        let network_icon = svg::<Theme>(network_icon_handle).width(16).height(16);
+
+        let header = row![network_icon, text(record.address.clone()).size(15)]
+            .spacing(6)
+            .align_y(Center);
+
+        let action_row = row![
+            text(format!("Action: {}", record.action)).size(13).style(text::secondary),
+            text(record.timestamp.to_rfc3339()).size(12).style(text::secondary),
+        ]
+        .spacing(15);
+
+        container(
+            column![
+                header,
+                risk_level_row::<ActivityMessage>(&record.advisory_level, None),
+                action_row,
+            ]
+            .spacing(6),
+        )
+        .padding(10)
+        .width(Length::Fill)
+        .style(container::rounded_box)
+        .into()
+    }
+}
+
+/// Shared risk-level tag rendering (a "Risk Level: <level>" pill, plus one
+/// tag per `Advisory` code/key=value when present) used by both
+/// `PromptWindow::wallet_risk_row` and `ActivityWindow::activity_row` — the
+/// activity log reuses the same visual language the live prompt uses.
+fn risk_level_row<'a, Message: 'a>(
+    advisory_level: &AdvisoryLevel,
+    advisory: Option<&Advisory>,
+) -> Row<'a, Message> {
+    let risk_tag_bg_color = match advisory_level {
+        AdvisoryLevel::Unknown => Color::from_str("#F0F0F0").unwrap(),
+        AdvisoryLevel::Known => Color::from_str("#F0F0F0").unwrap(),
+        AdvisoryLevel::Warning => Color::from_str("#FFD700").unwrap(),
+        AdvisoryLevel::Risky => Color::from_str("#FFA500").unwrap(),
+        AdvisoryLevel::Danger => Color::from_str("#FF4500").unwrap(),
+    };
+
+    let risk_level_title = text("Risk Level:").size(15).style(text::secondary);
+
+    let risk_level_tag = container(
+        text(format!("{:?}", advisory_level))
+            .size(15)
+            .color(Color::BLACK),
+    )
+    .padding([4, 8])
+    .style(move |_theme| container::Style {
+        background: Some(Background::Color(risk_tag_bg_color)),
+        border: border::rounded(4),
+        ..Default::default()
+    });
+
+    let mut risk_row = row![risk_level_title, risk_level_tag]
+        .spacing(10)
+        .align_y(Center);
+
+    if let Some(advisory) = advisory {
+        let code_tag = container(
+            text(advisory.code.to_string())
+                .size(15)
+                .color(Color::BLACK),
+        )
+        .padding([4, 8])
+        .style(|_theme| container::Style {
+            background: Some(Background::Color(Color::from_str("#E0E0E0").unwrap())),
+            border: border::rounded(4),
+            ..Default::default()
+        });
+
+        risk_row = risk_row.push(code_tag);
+
+        for (key, value) in &advisory.tags {
+            risk_row = risk_row.push(text(format!("{key}={value}")).size(13).style(text::secondary));
+        }
+    }
+
+    risk_row
 }
 
 // ------------------------------------------------------------------
@@ -767,12 +1336,62 @@ fn default_prompt_window_setting() -> window::Settings {
     }
 }
 
+fn default_activity_window_setting() -> window::Settings {
+    window::Settings {
+        size: (760.0, 520.0).into(),
+        position: window::Position::Centered,
+        level: window::Level::AlwaysOnTop,
+        resizable: true,
+        decorations: false,
+        transparent: true,
+        blur: true,
+        exit_on_close_request: false,
+        ..Default::default()
+    }
+}
+
 // ------------------------------------------------------------------
 //                            ENTRYPOINT
 // ------------------------------------------------------------------
+/// Spawns the local query API server on its own thread/runtime, separate
+/// from the iced event loop.
+/// Does nothing if `DOME3_API_KEY` is unset: an empty key would blake3-hash
+/// to the same digest as an empty `X-API-Key` header, turning "reject every
+/// request" into "accept an unauthenticated one", so an unconfigured key
+/// means no server instead of an open one.
+fn spawn_query_api_server() {
+    let api_key = std::env::var("DOME3_API_KEY").unwrap_or_default();
+    if api_key.is_empty() {
+        error!("DOME3_API_KEY is not set; the query API server will not start");
+        return;
+    }
+
+    thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().expect("Failed to new tokio::runtime::Runtime");
+
+        runtime.block_on(async {
+            let bind_addr = std::env::var("DOME3_API_BIND")
+                .unwrap_or_else(|_| "127.0.0.1:7890".to_string())
+                .parse()
+                .expect("DOME3_API_BIND must be a valid socket address");
+
+            let config = server::Config {
+                bind_addr,
+                api_key_hash: blake3::hash(api_key.as_bytes()),
+            };
+
+            if let Err(e) = server::serve(config).await {
+                error!("Query API server stopped unexpectedly: {e}");
+            }
+        });
+    });
+}
+
 fn main() -> iced::Result {
     env_logger::init();
 
+    spawn_query_api_server();
+
     iced::daemon(Daemon::new, Daemon::update, Daemon::view)
         .subscription(Daemon::subscribe)
         .title(Daemon::title)
@@ -1,5 +1,7 @@
 use crate::crypto::NetworkRecognition;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use strum_macros::Display;
 use strum_macros::EnumString;
 
@@ -8,29 +10,108 @@ pub struct AddressLabel {
     pub network: AddressFormat,
     pub address: String,
     pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advisory: Option<Advisory>,
 }
 
 impl From<&str> for AddressLabel {
     fn from(address: &str) -> Self {
+        if let Some((_, label)) = crate::registry::lookup_known_address(address) {
+            return label;
+        }
+
         Self {
             network: address.guess_network(),
             address: address.to_string(),
             label: "Unknown Wallet".to_string(),
+            advisory: None,
         }
     }
 }
 
+/// A stable, machine-readable reason code attached to an [`Advisory`].
+#[derive(Clone, Debug, Serialize, Deserialize, EnumString, PartialEq, Eq, Display)]
+pub enum AdvisoryCode {
+    Sanctioned,
+    PhishingReport,
+    MaliciousSpender,
+    MixerAssociated,
+    FreshAddress,
+    AddressPoisoning,
+}
+
+impl AdvisoryCode {
+    /// Starts building an [`Advisory`] for this code, attaching `(key, value)` as its first tag.
+    ///
+    /// e.g. `AdvisoryCode::Sanctioned.tag("source", "ofac")`
+    pub fn tag(self, key: impl Into<String>, value: impl Into<String>) -> Advisory {
+        Advisory::new(self).tag(key, value)
+    }
+}
+
+/// An [`AdvisoryCode`] plus a small set of string tags carrying
+/// machine-readable context about *why* the code was assigned (e.g.
+/// `source=chainalysis`), so the UI and logs can explain an advisory rather
+/// than just showing a flat risk level.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Advisory {
+    pub code: AdvisoryCode,
+    pub tags: BTreeMap<String, String>,
+}
+
+impl Advisory {
+    pub fn new(code: AdvisoryCode) -> Self {
+        Self {
+            code,
+            tags: BTreeMap::new(),
+        }
+    }
+
+    pub fn tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+}
+
 #[derive(Default, Copy, Clone, Debug, Serialize, Deserialize, EnumString, PartialEq, Display)]
 pub enum AddressFormat {
     Bitcoin,
+    BitcoinCash,
     EVM,
     Tron,
     Solana,
+    /// A Solana program-derived address (PDA): contract-controlled, not a
+    /// user's wallet key. See `crypto::NetworkRecognition::is_solana_pda`.
+    SolanaProgram,
+    /// An SS58 address with network identifier 0. See `crypto::ss58_chain_name`.
     Polkadot,
+    /// An SS58 address with network identifier 2. See `crypto::ss58_chain_name`.
+    Kusama,
+    /// An SS58 address with a network identifier other than Polkadot's or
+    /// Kusama's -- the generic "substrate" prefix (42) or an unrecognized
+    /// parachain-specific one. See `crypto::ss58_chain_name`.
+    Substrate,
     #[default]
     Other,
 }
 
+/// A user-initiated suppression rule, surfaced as buttons on `PromptWindow`.
+/// `SnoozeAll` is temporary and kept only in `Daemon`'s in-memory state; the
+/// other three are persisted in the label store so they survive a restart.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum MuteIntent {
+    SnoozeAll(std::time::Duration),
+    MuteAddress {
+        network: AddressFormat,
+        address: String,
+    },
+    MuteNetwork(AddressFormat),
+    AllowAddressForever {
+        network: AddressFormat,
+        address: String,
+    },
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum AdvisoryLevel {
     Unknown, // Waiting for querying result from 3rd-party APIs
@@ -39,3 +120,24 @@ pub enum AdvisoryLevel {
     Risky,   // Medium risk - detected some security concerns, not recommended to interact
     Danger,  // Severe risk - known malicious actor, DO NOT INTERACT
 }
+
+/// How a `PromptWindow` was resolved, for the activity log's audit trail.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Display)]
+pub enum ActivityAction {
+    Closed,
+    Muted,
+    LabelSaved,
+    AutoClosed,
+}
+
+/// A single append-only activity-log entry: a `PromptWindow` detection
+/// together with how the user (or the auto-close timer) resolved it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActivityRecord {
+    pub timestamp: DateTime<Utc>,
+    pub network: AddressFormat,
+    pub address: String,
+    pub advisory_level: AdvisoryLevel,
+    pub label: String,
+    pub action: ActivityAction,
+}
@@ -0,0 +1,126 @@
+//! Address-poisoning (dust-attack lookalike) detection.
+//!
+//! An adversary sends a dust transaction from an address that shares the
+//! same leading/trailing characters as an address the user has already
+//! transacted with, hoping the user later copies the poisoned lookalike out
+//! of their wallet history instead of the real one. This module compares a
+//! freshly-seen address against a trusted set of previously-used addresses
+//! on the same network and flags a lookalike before it ever reaches the
+//! label store.
+
+/// Number of leading/trailing characters compared between a candidate
+/// address and each trusted address.
+pub const DEFAULT_AFFIX_LEN: usize = 4;
+
+/// Edit distance (over the full address) at or below which a suffix-only match is
+/// still treated as a (looser) lookalike rather than an unrelated address.
+const LOOSE_EDIT_DISTANCE_THRESHOLD: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PoisoningMatch {
+    /// Shared prefix only, or a suffix match with a small edit distance —
+    /// plausibly coincidental, but worth a second look.
+    Loose,
+    /// Identical `k`-char prefix AND suffix with a different body — the
+    /// textbook poisoning pattern.
+    Exact,
+}
+
+/// Compares `candidate` against every address in `trusted` (same network),
+/// returning the most severe [`PoisoningMatch`] found, if any.
+pub fn detect(candidate: &str, trusted: &[String], affix_len: usize) -> Option<PoisoningMatch> {
+    trusted
+        .iter()
+        .filter_map(|known| classify(candidate, known, affix_len))
+        .max()
+}
+
+fn classify(candidate: &str, trusted: &str, affix_len: usize) -> Option<PoisoningMatch> {
+    if candidate == trusted {
+        return None;
+    }
+
+    let candidate = candidate.as_bytes();
+    let trusted = trusted.as_bytes();
+
+    if candidate.len() < affix_len * 2 || trusted.len() < affix_len * 2 {
+        return None;
+    }
+
+    let prefix_match = candidate[..affix_len] == trusted[..affix_len];
+    let suffix_match =
+        candidate[candidate.len() - affix_len..] == trusted[trusted.len() - affix_len..];
+
+    if prefix_match && suffix_match {
+        return Some(PoisoningMatch::Exact);
+    }
+
+    if prefix_match {
+        return Some(PoisoningMatch::Loose);
+    }
+
+    if suffix_match && levenshtein(candidate, trusted) <= LOOSE_EDIT_DISTANCE_THRESHOLD {
+        return Some(PoisoningMatch::Loose);
+    }
+
+    None
+}
+
+/// Classic Wagner-Fischer edit distance, operating on raw bytes since
+/// wallet addresses are ASCII.
+fn levenshtein(a: &[u8], b: &[u8]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0usize; b.len() + 1];
+
+    for (i, &a_byte) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, &b_byte) in b.iter().enumerate() {
+            let cost = if a_byte == b_byte { 0 } else { 1 };
+            cur[j + 1] = (prev[j + 1] + 1).min(cur[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_prefix_and_suffix_with_different_body_is_exact() {
+        let trusted = vec!["1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string()];
+        let candidate = "1A1zPXXXXXXXXXXXXXXXXXXXXXXXXivfNa";
+        assert_eq!(
+            detect(candidate, &trusted, DEFAULT_AFFIX_LEN),
+            Some(PoisoningMatch::Exact)
+        );
+    }
+
+    #[test]
+    fn shared_prefix_only_is_loose() {
+        let trusted = vec!["1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string()];
+        let candidate = "1A1zPyyyyyyyyyyyyyyyyyyyyyyyyyyyyy";
+        assert_eq!(
+            detect(candidate, &trusted, DEFAULT_AFFIX_LEN),
+            Some(PoisoningMatch::Loose)
+        );
+    }
+
+    #[test]
+    fn unrelated_address_is_not_flagged() {
+        let trusted = vec!["1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string()];
+        let candidate = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+        assert_eq!(detect(candidate, &trusted, DEFAULT_AFFIX_LEN), None);
+    }
+
+    #[test]
+    fn identical_address_is_not_flagged() {
+        let trusted = vec!["1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string()];
+        assert_eq!(
+            detect(trusted[0].as_str(), &trusted, DEFAULT_AFFIX_LEN),
+            None
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Coin/token metadata registry, SLIP-style (see SLIP-0044:
+//! https://github.com/satoshilabs/slips/blob/master/slip-0044.md): maps a
+//! well-known address -- a native coin's own address or a per-chain ERC20
+//! token contract -- to a human-readable label, so `AddressLabel::from`
+//! doesn't have to fall back to a bare "Unknown Wallet".
+
+use crate::crypto::NetworkRecognition;
+use crate::models::{AddressFormat, AddressLabel, AdvisoryLevel};
+use rust_embed::RustEmbed;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Chain names a token entry's `key` can carry that mark it as a testnet
+/// deployment, so its label can call that out instead of looking identical
+/// to the mainnet entry.
+const TESTNET_CHAIN_MARKERS: [&str; 5] = ["sepolia", "goerli", "holesky", "testnet", "devnet"];
+
+#[derive(RustEmbed)]
+#[folder = "registry/"]
+struct EmbeddedDefinitions;
+
+/// A single registry entry: a native coin's ticker (`"BTC"`) or a token's
+/// `erc20:<chain>:<symbol>` key (`"erc20:eth:USDT"`), and the address it
+/// identifies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinDefinition {
+    pub key: String,
+    pub label: String,
+    pub network: AddressFormat,
+    pub address: String,
+}
+
+impl CoinDefinition {
+    /// The chain segment of an `erc20:<chain>:<symbol>` key; `None` for a
+    /// bare native-coin key like `"BTC"`.
+    fn chain(&self) -> Option<&str> {
+        self.key.strip_prefix("erc20:")?.split(':').next()
+    }
+
+    /// Whether this entry's chain name/title looks like a testnet, derived
+    /// from its key rather than stored as a separate flag.
+    fn is_testnet(&self) -> bool {
+        let Some(chain) = self.chain() else {
+            return false;
+        };
+        let chain = chain.to_lowercase();
+        TESTNET_CHAIN_MARKERS.iter().any(|marker| chain.contains(marker))
+    }
+
+    /// The label to show the user, with a "(Testnet)" suffix for testnet entries.
+    fn display_label(&self) -> String {
+        if self.is_testnet() {
+            format!("{} (Testnet)", self.label)
+        } else {
+            self.label.clone()
+        }
+    }
+}
+
+/// Looks up addresses against a set of `CoinDefinition`s, keyed on
+/// `(network, address)`. Only EVM addresses are lowercased before keying,
+/// since EVM addresses are case-insensitive; Base58 formats (Bitcoin,
+/// Solana, Tron, Substrate, ...) are case-sensitive and lowercasing them
+/// risks colliding two distinct addresses onto the same key.
+pub struct CoinRegistry {
+    by_address: HashMap<(AddressFormat, String), CoinDefinition>,
+}
+
+impl CoinRegistry {
+    pub fn builder() -> CoinRegistryBuilder {
+        CoinRegistryBuilder::default()
+    }
+
+    /// Looks up `address` on `network`; `None` if it isn't a recognized
+    /// native coin address or token contract.
+    pub fn lookup(&self, network: &AddressFormat, address: &str) -> Option<&CoinDefinition> {
+        self.by_address.get(&(*network, registry_key(*network, address)))
+    }
+}
+
+/// Builds a [`CoinRegistry`], defaulting to empty so callers opt in to the
+/// dataset embedded in the binary and/or layer in their own definitions.
+#[derive(Default)]
+pub struct CoinRegistryBuilder {
+    definitions: Vec<CoinDefinition>,
+}
+
+impl CoinRegistryBuilder {
+    /// Adds every entry from `registry/*.json`, embedded into the binary.
+    pub fn with_embedded_dataset(mut self) -> Self {
+        self.definitions.extend(load_embedded_definitions());
+        self
+    }
+
+    /// Adds caller-supplied definitions, e.g. a user-provided bundle meant
+    /// to extend or replace the embedded dataset.
+    pub fn with_definitions(mut self, definitions: impl IntoIterator<Item = CoinDefinition>) -> Self {
+        self.definitions.extend(definitions);
+        self
+    }
+
+    pub fn build(self) -> CoinRegistry {
+        let by_address = self
+            .definitions
+            .into_iter()
+            .map(|def| ((def.network, registry_key(def.network, &def.address)), def))
+            .collect();
+        CoinRegistry { by_address }
+    }
+}
+
+/// The `HashMap` key for `address` on `network`: lowercased only for `EVM`,
+/// where addresses are case-insensitive; left as-is for every other,
+/// case-sensitive Base58 format.
+fn registry_key(network: AddressFormat, address: &str) -> String {
+    if network == AddressFormat::EVM {
+        address.to_lowercase()
+    } else {
+        address.to_string()
+    }
+}
+
+fn load_embedded_definitions() -> Vec<CoinDefinition> {
+    EmbeddedDefinitions::iter()
+        .filter_map(|path| EmbeddedDefinitions::get(&path))
+        .filter_map(|file| serde_json::from_slice::<Vec<CoinDefinition>>(&file.data).ok())
+        .flatten()
+        .collect()
+}
+
+/// The registry built from the dataset embedded in the binary; used by
+/// `AddressLabel::from` and `ConsensusEvaluator`'s no-provider-answered
+/// fallback.
+pub static DEFAULT_REGISTRY: LazyLock<CoinRegistry> =
+    LazyLock::new(|| CoinRegistry::builder().with_embedded_dataset().build());
+
+/// Looks up `address` in [`DEFAULT_REGISTRY`]; `Some` with `AdvisoryLevel::Known`
+/// and a labeled `AddressLabel` if it's a recognized native coin address or
+/// token contract, `None` otherwise.
+pub fn lookup_known_address(address: &str) -> Option<(AdvisoryLevel, AddressLabel)> {
+    let network = address.guess_network();
+    let definition = DEFAULT_REGISTRY.lookup(&network, address)?;
+
+    Some((
+        AdvisoryLevel::Known,
+        AddressLabel {
+            network,
+            address: address.to_string(),
+            label: definition.display_label(),
+            advisory: None,
+        },
+    ))
+}
@@ -0,0 +1,120 @@
+//! Local query API server.
+//!
+//! Exposes the same `externals::evaluate_all` pipeline the clipboard watcher
+//! uses over a small versioned JSON/RPC surface, so other local tools can
+//! query an address without going through the clipboard.
+
+use crate::crypto::NetworkRecognition;
+use crate::externals::evaluate_all;
+use crate::models::{AddressLabel, AdvisoryLevel};
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use log::{error, info};
+use serde::Serialize;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+const API_KEY_HEADER: &str = "X-API-Key";
+const FORBIDDEN_BODY: &str = "Forbidden: missing or invalid API key";
+
+#[derive(Clone)]
+pub struct Config {
+    pub bind_addr: SocketAddr,
+    /// BLAKE3 hash of the expected API key. The raw key itself is never stored.
+    pub api_key_hash: blake3::Hash,
+}
+
+#[derive(Clone)]
+struct AppState {
+    config: Arc<Config>,
+}
+
+#[derive(Serialize)]
+struct AddressResponse {
+    #[serde(rename = "advisoryLevel")]
+    advisory_level: AdvisoryLevel,
+    label: AddressLabel,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Run the server until the process is terminated.
+pub async fn serve(config: Config) -> anyhow::Result<()> {
+    let bind_addr = config.bind_addr;
+    let state = AppState {
+        config: Arc::new(config),
+    };
+
+    let app = Router::new()
+        .route("/v1/health", get(health_handler))
+        .route("/v1/address/{address}", get(address_handler))
+        .fallback(unknown_route_handler)
+        .with_state(state);
+
+    info!("🌐 Starting local query API server on {bind_addr}");
+    let listener = tokio::net::TcpListener::bind(bind_addr).await?;
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+async fn health_handler() -> impl IntoResponse {
+    Json(serde_json::json!({ "status": "ok" }))
+}
+
+async fn address_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(address): Path<String>,
+) -> Response {
+    if let Err(response) = authenticate(&state, &headers) {
+        return response;
+    }
+
+    let canonical_address = address.to_canonical_address().to_string();
+
+    match evaluate_all(&canonical_address).await {
+        Ok((advisory_level, label)) => Json(AddressResponse {
+            advisory_level,
+            label,
+        })
+        .into_response(),
+        Err(e) => {
+            error!("Failed to evaluate address {canonical_address}: {e}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to evaluate address".to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Catches unversioned/unknown paths (e.g. `/v2/...`) with a distinct error
+/// from the 403 the auth layer returns.
+async fn unknown_route_handler() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse {
+            error: "Unknown API version or path".to_string(),
+        }),
+    )
+        .into_response()
+}
+
+fn authenticate(state: &AppState, headers: &HeaderMap) -> Result<(), Response> {
+    let provided_key = headers.get(API_KEY_HEADER).and_then(|v| v.to_str().ok());
+
+    match provided_key {
+        Some(key) if blake3::hash(key.as_bytes()) == state.config.api_key_hash => Ok(()),
+        _ => Err((StatusCode::FORBIDDEN, FORBIDDEN_BODY).into_response()),
+    }
+}
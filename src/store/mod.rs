@@ -0,0 +1,307 @@
+use crate::crypto::NetworkRecognition;
+use crate::models::{ActivityRecord, AddressFormat, AddressLabel, MuteIntent};
+use anyhow::{Context, Result, anyhow};
+use directories::ProjectDirs;
+use heed::types::{SerdeJson, Str};
+use heed::{Database, Env, EnvOpenOptions};
+use log::{info, warn};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+const LABELS_DB_NAME: &str = "labels";
+const MUTES_DB_NAME: &str = "mutes";
+const ACTIVITY_DB_NAME: &str = "activity";
+const DEFAULT_MAP_SIZE: usize = 64 * 1024 * 1024; // 64 MiB LMDB map size, set once when the env is opened
+
+/// Embedded, transactional key-value store for `AddressLabel` records,
+/// keyed on `(network, address)`. Replaces the former append-only CSV file:
+/// every `upsert` overwrites the existing entry for a key instead of adding
+/// a duplicate row, and `get`/`list` read through a (read-only) LMDB
+/// transaction rather than scanning an in-memory `Vec`.
+pub struct Store {
+    env: Env,
+    labels: Database<Str, SerdeJson<AddressLabel>>,
+    mutes: Database<Str, SerdeJson<MuteIntent>>,
+    activity: Database<Str, SerdeJson<ActivityRecord>>,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        if let Some(project_dirs) = ProjectDirs::from("com", "dome3", "app") {
+            Self::open(project_dirs.data_dir().join("label_store"))
+                .expect("Failed to initialize label store")
+        } else {
+            Self::open("label_store").expect("Failed to initialize label store")
+        }
+    }
+}
+
+impl Store {
+    pub fn open<T: Into<PathBuf>>(dir: T) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir).context("Failed to create label store directory")?;
+
+        // SAFETY: `dir` is a directory dedicated to this store and not
+        // shared with another incompatible LMDB layout.
+        let env = unsafe {
+            EnvOpenOptions::new()
+                .map_size(DEFAULT_MAP_SIZE)
+                .max_dbs(3)
+                .open(&dir)
+        }
+        .context("Failed to open label store environment")?;
+
+        let mut wtxn = env.write_txn().context("Failed to start setup transaction")?;
+        let labels = env
+            .create_database(&mut wtxn, Some(LABELS_DB_NAME))
+            .context("Failed to create/open labels database")?;
+        let mutes = env
+            .create_database(&mut wtxn, Some(MUTES_DB_NAME))
+            .context("Failed to create/open mutes database")?;
+        let activity = env
+            .create_database(&mut wtxn, Some(ACTIVITY_DB_NAME))
+            .context("Failed to create/open activity database")?;
+        wtxn.commit().context("Failed to commit database setup")?;
+
+        Ok(Self {
+            env,
+            labels,
+            mutes,
+            activity,
+        })
+    }
+
+    /// Look up a previously stored label for `(network, address)`.
+    pub fn get(&self, network: &AddressFormat, address: &str) -> Result<Option<AddressLabel>> {
+        let rtxn = self.env.read_txn().context("Failed to start read txn")?;
+        self.labels
+            .get(&rtxn, &key_for(network, address))
+            .context("Failed to read from label store")
+    }
+
+    /// Insert or overwrite the entry for `record`'s `(network, address)`.
+    pub fn upsert(&self, record: AddressLabel) -> Result<()> {
+        let mut wtxn = self.env.write_txn().context("Failed to start write txn")?;
+        self.labels
+            .put(&mut wtxn, &key_for(&record.network, &record.address), &record)
+            .context("Failed to write to label store")?;
+        wtxn.commit().context("Failed to commit label store write")?;
+        Ok(())
+    }
+
+    /// All stored labels, in no particular order.
+    pub fn list(&self) -> Result<Vec<AddressLabel>> {
+        let rtxn = self.env.read_txn().context("Failed to start read txn")?;
+        self.labels
+            .iter(&rtxn)
+            .context("Failed to iterate label store")?
+            .map(|entry| entry.map(|(_key, record)| record).context("Failed to read label store entry"))
+            .collect()
+    }
+
+    /// Addresses the user has already saved a label for on `network` — the
+    /// trusted set that address-poisoning detection (see [`crate::poisoning`])
+    /// compares freshly-seen addresses against.
+    pub fn trusted_addresses(&self, network: &AddressFormat) -> Result<Vec<String>> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .filter(|record| record.network == *network)
+            .map(|record| record.address)
+            .collect())
+    }
+
+    /// Persists a non-temporary `MuteIntent` (`MuteAddress`, `MuteNetwork`, or
+    /// `AllowAddressForever`). `SnoozeAll` is intentionally rejected: it's a
+    /// temporary suppression the caller is expected to keep in memory instead.
+    pub fn mute(&self, intent: MuteIntent) -> Result<()> {
+        let key = match &intent {
+            MuteIntent::MuteAddress { network, address } => address_mute_key(network, address),
+            MuteIntent::MuteNetwork(network) => network_mute_key(network),
+            MuteIntent::AllowAddressForever { network, address } => {
+                allow_forever_key(network, address)
+            }
+            MuteIntent::SnoozeAll(_) => {
+                return Err(anyhow!("SnoozeAll is a temporary intent and cannot be persisted"));
+            }
+        };
+
+        let mut wtxn = self.env.write_txn().context("Failed to start write txn")?;
+        self.mutes
+            .put(&mut wtxn, &key, &intent)
+            .context("Failed to write mute rule")?;
+        wtxn.commit().context("Failed to commit mute rule")?;
+        Ok(())
+    }
+
+    /// Whether `(network, address)` should be suppressed by a persisted mute
+    /// rule. An `AllowAddressForever` rule for this address takes precedence
+    /// over both an address-level and a network-level mute.
+    pub fn is_muted(&self, network: &AddressFormat, address: &str) -> Result<bool> {
+        let rtxn = self.env.read_txn().context("Failed to start read txn")?;
+
+        if self
+            .mutes
+            .get(&rtxn, &allow_forever_key(network, address))
+            .context("Failed to read allow-forever rule")?
+            .is_some()
+        {
+            return Ok(false);
+        }
+
+        if self
+            .mutes
+            .get(&rtxn, &address_mute_key(network, address))
+            .context("Failed to read address mute rule")?
+            .is_some()
+        {
+            return Ok(true);
+        }
+
+        self.mutes
+            .get(&rtxn, &network_mute_key(network))
+            .context("Failed to read network mute rule")
+            .map(|entry| entry.is_some())
+    }
+
+    /// Appends an [`ActivityRecord`] to the append-only activity log. The key
+    /// is derived from the record's nanosecond timestamp, so records sort
+    /// oldest-to-newest by key; a record with the exact same timestamp as a
+    /// prior one replaces it.
+    pub fn log_activity(&self, record: ActivityRecord) -> Result<()> {
+        let key = activity_key(&record.timestamp);
+        let mut wtxn = self.env.write_txn().context("Failed to start write txn")?;
+        self.activity
+            .put(&mut wtxn, &key, &record)
+            .context("Failed to write activity log entry")?;
+        wtxn.commit().context("Failed to commit activity log entry")?;
+        Ok(())
+    }
+
+    /// The `limit` most recent activity-log entries, newest first.
+    pub fn recent_activity(&self, limit: usize) -> Result<Vec<ActivityRecord>> {
+        let rtxn = self.env.read_txn().context("Failed to start read txn")?;
+        self.activity
+            .rev_iter(&rtxn)
+            .context("Failed to iterate activity log")?
+            .take(limit)
+            .map(|entry| entry.map(|(_key, record)| record).context("Failed to read activity log entry"))
+            .collect()
+    }
+
+    /// Export every stored label to a BIP-329-agnostic flat CSV file, purely
+    /// for portability; the store itself remains the source of truth.
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> Result<()> {
+        let records = self.list()?;
+        let mut writer =
+            csv::Writer::from_path(path.as_ref()).context("Unable to create CSV export file")?;
+
+        for record in &records {
+            writer
+                .serialize(record)
+                .context("Failed to serialize record to CSV")?;
+        }
+
+        writer.flush().context("Failed to flush CSV export file")?;
+        Ok(())
+    }
+
+    /// Exports every stored label as BIP-329 (https://github.com/bitcoin/bips/blob/master/bip-0329.mediawiki)
+    /// JSONL, one `{"type":"addr","ref":...,"label":...}` object per line — the same
+    /// labeling format Liana speaks in its `Labels` message.
+    pub fn export_bip329(&self, path: impl AsRef<Path>) -> Result<()> {
+        let records = self.list()?;
+        let mut file = File::create(path.as_ref()).context("Unable to create BIP-329 export file")?;
+
+        for record in &records {
+            let line = serde_json::json!({
+                "type": "addr",
+                "ref": record.address,
+                "label": record.label,
+            });
+            writeln!(file, "{line}").context("Failed to write BIP-329 export line")?;
+        }
+
+        Ok(())
+    }
+
+    /// Imports BIP-329 JSONL labels, returning the number of records upserted.
+    ///
+    /// Only `"type": "addr"` records carry a wallet-address label; every other
+    /// BIP-329 type (`tx`, `pubkey`, `input`, `output`, `xpub`) is ignored. A
+    /// malformed line is logged and skipped rather than aborting the whole
+    /// import, and an imported value always overwrites an existing entry
+    /// (logged when that happens).
+    pub fn import_bip329(&self, path: impl AsRef<Path>) -> Result<usize> {
+        let file = File::open(path.as_ref()).context("Unable to open BIP-329 import file")?;
+        let mut imported = 0;
+
+        for (line_no, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.context("Failed to read BIP-329 import file")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: serde_json::Value = match serde_json::from_str(&line) {
+                Ok(record) => record,
+                Err(e) => {
+                    warn!("Skipping malformed BIP-329 line {}: {e}", line_no + 1);
+                    continue;
+                }
+            };
+
+            if record.get("type").and_then(|v| v.as_str()) != Some("addr") {
+                continue;
+            }
+
+            let (Some(address), Some(label)) = (
+                record.get("ref").and_then(|v| v.as_str()),
+                record.get("label").and_then(|v| v.as_str()),
+            ) else {
+                warn!(
+                    "Skipping BIP-329 addr record missing ref/label at line {}",
+                    line_no + 1
+                );
+                continue;
+            };
+
+            let network = address.guess_network();
+            let address = address.to_canonical_address();
+
+            if self.get(&network, &address)?.is_some() {
+                info!("Overwriting existing label for {address} from BIP-329 import");
+            }
+
+            self.upsert(AddressLabel {
+                network,
+                address: address.to_string(),
+                label: label.to_string(),
+                advisory: None,
+            })?;
+            imported += 1;
+        }
+
+        Ok(imported)
+    }
+}
+
+fn key_for(network: &AddressFormat, address: &str) -> String {
+    format!("{network:?}:{address}")
+}
+
+fn address_mute_key(network: &AddressFormat, address: &str) -> String {
+    format!("mute:address:{network:?}:{address}")
+}
+
+fn network_mute_key(network: &AddressFormat) -> String {
+    format!("mute:network:{network:?}")
+}
+
+fn allow_forever_key(network: &AddressFormat, address: &str) -> String {
+    format!("allow:{network:?}:{address}")
+}
+
+fn activity_key(timestamp: &chrono::DateTime<chrono::Utc>) -> String {
+    format!("{:020}", timestamp.timestamp_nanos_opt().unwrap_or(0))
+}